@@ -0,0 +1,19 @@
+use soroban_sdk::{contractclient, contracttype, Address, Env, Vec};
+
+/// The global configuration of an admin transfer escrow. Must match the `Config` type of the
+/// admin transfer contract.
+#[contracttype]
+#[derive(Clone)]
+pub struct Config {
+    pub default_timelock: u32,
+    pub max_expiry: u32,
+    pub trusted_factories: Vec<Address>,
+    pub guardian: Address,
+    pub cooldown: u32,
+}
+
+/// The subset of the admin transfer contract interface used by the factory
+#[contractclient(name = "Client")]
+pub trait AdminTransfer {
+    fn initialize(e: Env, owner: Address, config: Config, pool: Option<Address>);
+}
@@ -0,0 +1,104 @@
+use soroban_sdk::{
+    assert_with_error, contract, contractimpl, panic_with_error, xdr::ToXdr, Address, BytesN, Env,
+    Symbol,
+};
+
+use crate::{
+    admin_transfer::{self, Config},
+    errors::EscrowFactoryError,
+    storage::{self, EscrowFactoryConfig},
+};
+
+#[contract]
+pub struct EscrowFactory;
+
+#[contractimpl]
+impl EscrowFactory {
+    /// Initialize the escrow factory
+    ///
+    /// ### Arguments
+    /// * `owner` - The owner of every escrow deployed by the factory
+    /// * `escrow_wasm_hash` - The WASM hash of the admin transfer escrow contract
+    /// * `escrow_config` - The global configuration every escrow is initialized with
+    ///
+    /// ### Panics
+    /// * `AlreadyInitializedError` - If the factory has already been initialized
+    pub fn initialize(e: Env, owner: Address, escrow_wasm_hash: BytesN<32>, escrow_config: Config) {
+        assert_with_error!(
+            &e,
+            !storage::has_config(&e),
+            EscrowFactoryError::AlreadyInitializedError
+        );
+        storage::extend_instance(&e);
+
+        storage::set_config(
+            &e,
+            &EscrowFactoryConfig {
+                owner,
+                escrow_wasm_hash,
+                escrow_config,
+            },
+        );
+    }
+
+    /// Deploy and initialize the escrow for a pool. The escrow address is derived from the pool,
+    /// and the escrow only accepts admin transfers for the pool.
+    ///
+    /// Returns the address of the escrow
+    ///
+    /// ### Arguments
+    /// * `pool` - The address of the pool the escrow is for
+    ///
+    /// ### Panics
+    /// * `NotInitialized` - If the factory has not been initialized
+    /// * `EscrowExists` - If an escrow has already been deployed for the pool
+    pub fn deploy(e: Env, pool: Address) -> Address {
+        let config = match storage::get_config(&e) {
+            Some(config) => config,
+            None => panic_with_error!(&e, EscrowFactoryError::NotInitialized),
+        };
+        storage::extend_instance(&e);
+
+        let deployer = e.deployer().with_current_contract(escrow_salt(&e, &pool));
+        assert_with_error!(
+            &e,
+            !storage::is_deployed(&e, &deployer.deployed_address()),
+            EscrowFactoryError::EscrowExists
+        );
+        let escrow = deployer.deploy(config.escrow_wasm_hash);
+        admin_transfer::Client::new(&e, &escrow).initialize(
+            &config.owner,
+            &config.escrow_config,
+            &Some(pool.clone()),
+        );
+
+        storage::set_deployed(&e, &escrow);
+
+        e.events()
+            .publish((Symbol::new(&e, "deploy"), pool), escrow.clone());
+        escrow
+    }
+
+    /// Get the escrow address for a pool. The escrow may not have been deployed yet.
+    ///
+    /// ### Arguments
+    /// * `pool` - The address of the pool the escrow is for
+    pub fn escrow_address(e: Env, pool: Address) -> Address {
+        e.deployer()
+            .with_current_contract(escrow_salt(&e, &pool))
+            .deployed_address()
+    }
+
+    /// Check if an address is an escrow deployed by the factory
+    ///
+    /// ### Arguments
+    /// * `escrow` - The address to check
+    pub fn is_escrow(e: Env, escrow: Address) -> bool {
+        storage::is_deployed(&e, &escrow)
+    }
+}
+
+/// Derive the salt used to deploy the escrow for a pool
+fn escrow_salt(e: &Env, pool: &Address) -> BytesN<32> {
+    e.crypto().sha256(&pool.clone().to_xdr(e))
+}
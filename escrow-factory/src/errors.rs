@@ -0,0 +1,14 @@
+use soroban_sdk::contracterror;
+
+/// The error codes for the contract.
+#[contracterror]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum EscrowFactoryError {
+    // Default errors to align with built-in contract
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+
+    // Custom errors
+    NotInitialized = 100,
+    EscrowExists = 101,
+}
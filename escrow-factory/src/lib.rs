@@ -0,0 +1,14 @@
+#![no_std]
+
+#[cfg(test)]
+extern crate std;
+
+pub mod admin_transfer;
+mod contract;
+mod errors;
+mod storage;
+
+pub use contract::*;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,83 @@
+use soroban_sdk::{contracttype, Address, BytesN, Env};
+
+use crate::admin_transfer::Config;
+
+#[contracttype]
+pub struct EscrowFactoryConfig {
+    pub owner: Address,
+    pub escrow_wasm_hash: BytesN<32>,
+    pub escrow_config: Config,
+}
+
+//********** Storage Keys **********//
+
+#[contracttype]
+pub enum DataKey {
+    // Instance
+    Config,
+    // Persistent
+    Escrow(Address),
+}
+
+//********** Storage Utils **********//
+
+pub const ONE_DAY_LEDGERS: u32 = 17280; // assumes 5 seconds per ledger on average
+
+const LEDGER_BUMP_SHARED: u32 = 31 * ONE_DAY_LEDGERS;
+const LEDGER_THRESHOLD_SHARED: u32 = LEDGER_BUMP_SHARED - ONE_DAY_LEDGERS;
+
+const LEDGER_BUMP_ESCROW: u32 = 120 * ONE_DAY_LEDGERS;
+const LEDGER_THRESHOLD_ESCROW: u32 = LEDGER_BUMP_ESCROW - 20 * ONE_DAY_LEDGERS;
+
+/// Bump the instance lifetime by the defined amount
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/********** Instance **********/
+
+/// Check if the factory has been initialized
+pub fn has_config(e: &Env) -> bool {
+    e.storage().instance().has(&DataKey::Config)
+}
+
+/// Get the factory config
+pub fn get_config(e: &Env) -> Option<EscrowFactoryConfig> {
+    e.storage().instance().get(&DataKey::Config)
+}
+
+/// Set the factory config
+///
+/// ### Arguments
+/// * `config` - The factory config
+pub fn set_config(e: &Env, config: &EscrowFactoryConfig) {
+    e.storage()
+        .instance()
+        .set::<DataKey, EscrowFactoryConfig>(&DataKey::Config, config);
+}
+
+/********** Persistent **********/
+
+/// Check if an address is an escrow deployed by the factory
+///
+/// ### Arguments
+/// * `escrow` - The address to check
+pub fn is_deployed(e: &Env, escrow: &Address) -> bool {
+    e.storage()
+        .persistent()
+        .has(&DataKey::Escrow(escrow.clone()))
+}
+
+/// Record an escrow deployed by the factory
+///
+/// ### Arguments
+/// * `escrow` - The address of the deployed escrow
+pub fn set_deployed(e: &Env, escrow: &Address) {
+    let key = DataKey::Escrow(escrow.clone());
+    e.storage().persistent().set::<DataKey, bool>(&key, &true);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_ESCROW, LEDGER_BUMP_ESCROW);
+}
@@ -0,0 +1,102 @@
+#![cfg(test)]
+
+use crate::{admin_transfer::Config, errors::EscrowFactoryError, EscrowFactoryClient};
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    vec, Address, Env, Error, IntoVal, Symbol,
+};
+
+mod admin_transfer_wasm {
+    soroban_sdk::contractimport!(
+        file = "../target/wasm32-unknown-unknown/optimized/pool_admin_transfer.wasm"
+    );
+}
+
+#[test]
+fn test_deploy_escrow() {
+    let env = Env::default();
+
+    let factory_id = env.register_contract(None, crate::EscrowFactory);
+    let factory_client = EscrowFactoryClient::new(&env, &factory_id);
+
+    let owner = Address::generate(&env);
+    let pool = Address::generate(&env);
+    let pool_2 = Address::generate(&env);
+
+    let escrow_wasm_hash = env
+        .deployer()
+        .upload_contract_wasm(admin_transfer_wasm::WASM);
+    let escrow_config = Config {
+        default_timelock: 0,
+        max_expiry: 17280 * 30,
+        trusted_factories: vec![&env, factory_id.clone()],
+        guardian: owner.clone(),
+        cooldown: 0,
+    };
+
+    // validate an escrow cannot be deployed before initialization
+    let result = factory_client.try_deploy(&pool);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            EscrowFactoryError::NotInitialized as u32
+        )))
+    );
+
+    factory_client.initialize(&owner, &escrow_wasm_hash, &escrow_config);
+
+    // validate the factory cannot be initialized twice
+    let result = factory_client.try_initialize(&owner, &escrow_wasm_hash, &escrow_config);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            EscrowFactoryError::AlreadyInitializedError as u32
+        )))
+    );
+
+    // deploy an escrow
+    let expected_escrow = factory_client.escrow_address(&pool);
+    assert!(!factory_client.is_escrow(&expected_escrow));
+    let escrow = factory_client.deploy(&pool);
+
+    // -> validate event
+    let events = env.events().all();
+    let event = vec![&env, events.get_unchecked(events.len() - 1)];
+    assert_eq!(
+        event,
+        vec![
+            &env,
+            (
+                factory_id.clone(),
+                (Symbol::new(&env, "deploy"), pool.clone()).into_val(&env),
+                escrow.clone().into_val(&env)
+            )
+        ]
+    );
+
+    // -> validate chain state
+    assert_eq!(escrow, expected_escrow);
+    assert!(factory_client.is_escrow(&escrow));
+    assert!(!factory_client.is_escrow(&pool));
+    let escrow_client = admin_transfer_wasm::Client::new(&env, &escrow);
+    assert_eq!(escrow_client.get_owner(), Some(owner.clone()));
+    assert_eq!(escrow_client.get_pool(), Some(pool.clone()));
+    assert_eq!(
+        escrow_client.get_config().unwrap().trusted_factories,
+        vec![&env, factory_id.clone()]
+    );
+
+    // validate each pool gets its own escrow
+    let escrow_2 = factory_client.deploy(&pool_2);
+    assert_ne!(escrow, escrow_2);
+    assert!(factory_client.is_escrow(&escrow_2));
+
+    // validate a second escrow cannot be deployed for a pool
+    let result = factory_client.try_deploy(&pool);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            EscrowFactoryError::EscrowExists as u32
+        )))
+    );
+}
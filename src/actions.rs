@@ -0,0 +1,29 @@
+use blend_contract_sdk::pool::Client;
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+/// A pool admin action executed as part of an admin transfer
+#[contracttype]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum PoolAction {
+    /// Update the pool's backstop take rate and max positions
+    UpdatePool(u32, u32),
+    /// Set the status of the pool
+    SetStatus(u32),
+}
+
+/// Execute pool admin actions in order. The escrow must be the admin of the pool.
+///
+/// ### Arguments
+/// * `pool` - The address of the pool
+/// * `actions` - The pool admin actions to execute
+pub fn execute_pool_actions(e: &Env, pool: &Address, actions: &Vec<PoolAction>) {
+    let pool_client = Client::new(e, pool);
+    for action in actions.iter() {
+        match action {
+            PoolAction::UpdatePool(backstop_take_rate, max_positions) => {
+                pool_client.update_pool(&backstop_take_rate, &max_positions)
+            }
+            PoolAction::SetStatus(status) => pool_client.set_status(&status),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use blend_contract_sdk::pool;
+use soroban_sdk::{
+    contracttype, token::StellarAssetClient, vec, Address, Env, IntoVal, Symbol, Val,
+};
+
+/// The kind of contract an admin transfer is for
+#[contracttype]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum TargetKind {
+    /// A Blend lending pool
+    BlendPool,
+    /// A Stellar Asset Contract
+    StellarAsset,
+    /// A contract whose admin is set by calling the given function with the new admin as the
+    /// only argument
+    Contract(Symbol),
+    /// A contract with a two-step admin handover. The first function proposes the new admin as
+    /// its only argument, and the second function is the new admin's acceptance with no arguments.
+    TwoStep(Symbol, Symbol),
+}
+
+/// Set the admin of a target contract
+///
+/// For two-step targets, the new admin is proposed and the acceptance is invoked immediately,
+/// so the new admin must have authorized the acceptance as part of the current invocation.
+///
+/// ### Arguments
+/// * `target` - The address of the contract
+/// * `kind` - The kind of contract
+/// * `new_admin` - The new admin of the contract
+pub fn set_admin(e: &Env, target: &Address, kind: &TargetKind, new_admin: &Address) {
+    match kind {
+        TargetKind::BlendPool => pool::Client::new(e, target).set_admin(new_admin),
+        TargetKind::StellarAsset => StellarAssetClient::new(e, target).set_admin(new_admin),
+        TargetKind::Contract(fn_name) => {
+            e.invoke_contract::<Val>(target, fn_name, vec![e, new_admin.into_val(e)]);
+        }
+        TargetKind::TwoStep(propose_fn_name, accept_fn_name) => {
+            e.invoke_contract::<Val>(target, propose_fn_name, vec![e, new_admin.into_val(e)]);
+            e.invoke_contract::<Val>(target, accept_fn_name, vec![e]);
+        }
+    }
+}
+
+/// Propose a new admin of a target contract without completing the handover
+///
+/// For two-step targets, only the proposal is invoked and the new admin must accept the handover
+/// on the target directly. For all other targets, the admin is set immediately.
+///
+/// ### Arguments
+/// * `target` - The address of the contract
+/// * `kind` - The kind of contract
+/// * `new_admin` - The new admin of the contract
+pub fn propose_admin(e: &Env, target: &Address, kind: &TargetKind, new_admin: &Address) {
+    match kind {
+        TargetKind::TwoStep(propose_fn_name, _) => {
+            e.invoke_contract::<Val>(target, propose_fn_name, vec![e, new_admin.into_val(e)]);
+        }
+        _ => set_admin(e, target, kind, new_admin),
+    }
+}
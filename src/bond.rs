@@ -0,0 +1,108 @@
+use soroban_sdk::{contracttype, token::TokenClient, Address, Env};
+
+use crate::storage;
+
+/// The bond the current admin posts when an admin transfer is created
+#[contracttype]
+#[derive(Clone)]
+pub struct BondConfig {
+    /// The token the bond is posted in
+    pub token: Address,
+    /// The amount of tokens posted
+    pub amount: i128,
+    /// The share of the bond forfeited when the admin transfer is cancelled, in basis points
+    pub forfeit_bps: u32,
+    /// The address forfeited bonds are sent to
+    pub treasury: Address,
+}
+
+/// A bond held for a pending admin transfer. Keeps the terms it was posted under.
+#[contracttype]
+#[derive(Clone)]
+pub struct Bond {
+    pub token: Address,
+    pub amount: i128,
+    pub forfeit_bps: u32,
+    pub treasury: Address,
+}
+
+/// The running bond accounting for a token
+#[contracttype]
+#[derive(Clone, Default)]
+pub struct BondTotals {
+    /// The amount of bonds currently held
+    pub held: i128,
+    /// The total amount of bonds returned to current admins
+    pub returned: i128,
+    /// The total amount of bonds forfeited to treasuries
+    pub forfeited: i128,
+}
+
+/// The number of basis points in 100%
+pub const MAX_BPS: u32 = 10_000;
+
+/// Post a bond from the current admin of an admin transfer. The current admin must have
+/// authorized the invocation.
+///
+/// ### Arguments
+/// * `pool` - The address of the pool the admin transfer is for
+/// * `cur_admin` - The current admin posting the bond
+/// * `config` - The bond to post
+pub fn post_bond(e: &Env, pool: &Address, cur_admin: &Address, config: BondConfig) {
+    TokenClient::new(e, &config.token).transfer(
+        cur_admin,
+        &e.current_contract_address(),
+        &config.amount,
+    );
+
+    let mut totals = storage::get_bond_totals(e, &config.token);
+    totals.held += config.amount;
+    storage::set_bond_totals(e, &config.token, &totals);
+
+    storage::set_bond(
+        e,
+        pool,
+        &Bond {
+            token: config.token,
+            amount: config.amount,
+            forfeit_bps: config.forfeit_bps,
+            treasury: config.treasury,
+        },
+    );
+}
+
+/// Settle the bond of an admin transfer, if any. The bond is returned to the current admin,
+/// except for the forfeited share which is sent to the treasury.
+///
+/// ### Arguments
+/// * `pool` - The address of the pool the admin transfer is for
+/// * `cur_admin` - The current admin that posted the bond
+/// * `forfeit` - Whether the forfeited share of the bond is sent to the treasury
+pub fn settle_bond(e: &Env, pool: &Address, cur_admin: &Address, forfeit: bool) {
+    let bond = match storage::get_bond(e, pool) {
+        Some(bond) => bond,
+        None => return,
+    };
+    let forfeited = if forfeit {
+        bond.amount * bond.forfeit_bps as i128 / MAX_BPS as i128
+    } else {
+        0
+    };
+    let returned = bond.amount - forfeited;
+
+    let token_client = TokenClient::new(e, &bond.token);
+    if forfeited > 0 {
+        token_client.transfer(&e.current_contract_address(), &bond.treasury, &forfeited);
+    }
+    if returned > 0 {
+        token_client.transfer(&e.current_contract_address(), cur_admin, &returned);
+    }
+
+    let mut totals = storage::get_bond_totals(e, &bond.token);
+    totals.held -= bond.amount;
+    totals.returned += returned;
+    totals.forfeited += forfeited;
+    storage::set_bond_totals(e, &bond.token, &totals);
+
+    storage::del_bond(e, pool);
+}
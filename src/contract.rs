@@ -1,9 +1,27 @@
 use blend_contract_sdk::pool::Client;
-use soroban_sdk::{assert_with_error, contract, contractimpl, panic_with_error, Address, Env};
+use soroban_sdk::{
+    assert_with_error, contract, contractimpl, panic_with_error, unwrap::UnwrapOptimized,
+    xdr::ToXdr, Address, Bytes, BytesN, Env, Symbol, TryFromVal, Val, Vec,
+};
 
 use crate::{
+    actions::{self, PoolAction},
+    adapter::{self, TargetKind},
+    bond::{self, Bond, BondConfig, BondTotals},
     errors::ContractError,
-    storage::{self, TransferData},
+    events::AdminTransferEvents,
+    factory::EscrowFactoryClient,
+    governor::{GovernorClient, ProposalAction, ProposalStatus},
+    health::{self, HealthConfig, HealthFailure},
+    policy::{TransferAction, TransferPolicyClient},
+    receiver::AdminReceiverClient,
+    reserves,
+    storage::{
+        self, Config, Deployment, EscrowStatus, Hashlock, NewAdmin, OwnerProposal, PolicySource,
+        ProposalReference, QueuedReserveMode, QueuedUpgrade, ReceiveHook, TransferData,
+        TransferOptions,
+    },
+    version::{self, VersionInfo},
 };
 
 #[contract]
@@ -11,33 +29,679 @@ pub struct AdminTransfer;
 
 #[contractimpl]
 impl AdminTransfer {
+    /// Initialize the contract
+    ///
+    /// Anyone can initialize the contract, so it must be deployed and initialized atomically in
+    /// the same transaction, as the escrow factory does, or it can be initialized by another
+    /// account first.
+    ///
+    /// A contract upgraded from storage version 1 has no instance entries, so it is initialized
+    /// like a new contract before its pending admin transfers are migrated with
+    /// `migrate_admin_transfers`.
+    ///
+    /// ### Arguments
+    /// * `owner` - The owner of the contract, who manages the approved new admins
+    /// * `config` - The global configuration of the contract
+    /// * `pool` - The only pool the contract is an escrow for, or None to allow any pool
+    ///
+    /// ### Panics
+    /// * `AlreadyInitializedError` - If the contract has already been initialized
+    /// * `InvalidExpiry` - If the default timelock is not less than the maximum expiry
+    pub fn initialize(e: Env, owner: Address, config: Config, pool: Option<Address>) {
+        assert_with_error!(
+            &e,
+            !storage::has_owner(&e),
+            ContractError::AlreadyInitializedError
+        );
+        validate_config(&e, &config);
+        storage::extend_instance(&e);
+
+        storage::set_owner(&e, &owner);
+        storage::set_config(&e, &config);
+        if let Some(pool) = pool {
+            storage::set_pool(&e, &pool);
+        }
+        storage::set_storage_version(&e, storage::STORAGE_VERSION);
+    }
+
+    /// Get the owner of the contract
+    pub fn get_owner(e: Env) -> Option<Address> {
+        storage::get_owner(&e)
+    }
+
+    /// Get the only pool the contract is an escrow for, or None if it is an escrow for any pool
+    pub fn get_pool(e: Env) -> Option<Address> {
+        storage::get_pool(&e)
+    }
+
+    /// Propose a new owner of the contract. The proposal expires after the maximum expiry, and
+    /// an expired proposal is replaced. Must be called by the owner.
+    ///
+    /// ### Arguments
+    /// * `new_owner` - The proposed new owner
+    ///
+    /// ### Panics
+    /// * `NotInitialized` - If the contract has not been initialized
+    /// * `OwnerProposalExists` - If a new owner has already been proposed and has not expired
+    /// * `NewAdminIsCurrentAdmin` - If the proposed new owner is the owner
+    pub fn propose_owner(e: Env, new_owner: Address) {
+        let (owner, config) = require_owner_config(&e);
+        if let Some(proposal) = storage::get_owner_proposal(&e) {
+            assert_with_error!(
+                &e,
+                e.ledger().sequence() >= proposal.expiration_ledger,
+                ContractError::OwnerProposalExists
+            );
+        }
+        assert_with_error!(
+            &e,
+            new_owner != owner,
+            ContractError::NewAdminIsCurrentAdmin
+        );
+        storage::extend_instance(&e);
+
+        let proposal = OwnerProposal {
+            new_owner,
+            expiration_ledger: e.ledger().sequence().saturating_add(config.max_expiry),
+        };
+        storage::set_owner_proposal(&e, &Some(proposal.clone()));
+
+        AdminTransferEvents::propose_owner(&e, owner, proposal);
+    }
+
+    /// Accept ownership of the contract. Must be called by the proposed new owner.
+    ///
+    /// ### Panics
+    /// * `NoOwnerProposal` - If no new owner has been proposed
+    /// * `OwnerProposalExpired` - If the proposal has expired
+    pub fn accept_owner(e: Env) {
+        let proposal = match storage::get_owner_proposal(&e) {
+            Some(proposal) => proposal,
+            None => panic_with_error!(&e, ContractError::NoOwnerProposal),
+        };
+        assert_with_error!(
+            &e,
+            e.ledger().sequence() < proposal.expiration_ledger,
+            ContractError::OwnerProposalExpired
+        );
+        proposal.new_owner.require_auth();
+        storage::extend_instance(&e);
+
+        let prev_owner = storage::get_owner(&e).unwrap_optimized();
+        storage::set_owner(&e, &proposal.new_owner);
+        storage::set_owner_proposal(&e, &None);
+
+        AdminTransferEvents::accept_owner(&e, proposal.new_owner, prev_owner);
+    }
+
+    /// Cancel the proposed new owner. Expired proposals can be cancelled. Must be called by
+    /// the owner.
+    ///
+    /// ### Panics
+    /// * `NotInitialized` - If the contract has not been initialized
+    /// * `NoOwnerProposal` - If no new owner has been proposed
+    pub fn cancel_owner_proposal(e: Env) {
+        let owner = require_owner(&e);
+        let proposal = match storage::get_owner_proposal(&e) {
+            Some(proposal) => proposal,
+            None => panic_with_error!(&e, ContractError::NoOwnerProposal),
+        };
+        storage::extend_instance(&e);
+
+        storage::set_owner_proposal(&e, &None);
+
+        AdminTransferEvents::cancel_owner_proposal(&e, owner, proposal.new_owner);
+    }
+
+    /// Set the number of ledgers after an admin transfer completes before a new admin transfer
+    /// can be created for the same pool. Pools already in cooldown keep their cooldown.
+    /// Must be called by the owner.
+    ///
+    /// ### Arguments
+    /// * `cooldown` - The cooldown in ledgers
+    ///
+    /// ### Panics
+    /// * `NotInitialized` - If the contract has not been initialized
+    pub fn set_cooldown(e: Env, cooldown: u32) {
+        let (owner, mut config) = require_owner_config(&e);
+        config.cooldown = cooldown;
+        set_config(&e, owner, config);
+    }
+
+    /// Get the ledger sequence the cooldown of a pool ends at. New admin transfers for the pool
+    /// are rejected before this ledger. Returns 0 if the pool has never completed a transfer.
+    ///
+    /// ### Arguments
+    /// * `pool` - The address of the pool
+    pub fn get_cooldown(e: Env, pool: Address) -> u32 {
+        storage::get_cooldown(&e, &pool)
+    }
+
+    /// Get the proposed new owner of the contract
+    pub fn get_owner_proposal(e: Env) -> Option<OwnerProposal> {
+        storage::get_owner_proposal(&e)
+    }
+
+    /// Get the global configuration of the contract
+    pub fn get_config(e: Env) -> Option<Config> {
+        storage::get_config(&e)
+    }
+
+    /// Set the number of ledgers an admin transfer is locked for when the transfer does not set
+    /// its own timelock. Must be called by the owner.
+    ///
+    /// ### Arguments
+    /// * `default_timelock` - The default timelock in ledgers
+    ///
+    /// ### Panics
+    /// * `NotInitialized` - If the contract has not been initialized
+    /// * `InvalidExpiry` - If the default timelock is not less than the maximum expiry
+    pub fn set_default_timelock(e: Env, default_timelock: u32) {
+        let (owner, mut config) = require_owner_config(&e);
+        config.default_timelock = default_timelock;
+        set_config(&e, owner, config);
+    }
+
+    /// Set the maximum number of ledgers an admin transfer can be pending for. Pending admin
+    /// transfers keep their expiration. Must be called by the owner.
+    ///
+    /// ### Arguments
+    /// * `max_expiry` - The maximum expiry in ledgers
+    ///
+    /// ### Panics
+    /// * `NotInitialized` - If the contract has not been initialized
+    /// * `InvalidExpiry` - If the default timelock is not less than the maximum expiry
+    pub fn set_max_expiry(e: Env, max_expiry: u32) {
+        let (owner, mut config) = require_owner_config(&e);
+        config.max_expiry = max_expiry;
+        set_config(&e, owner, config);
+    }
+
+    /// Set the escrow factories whose escrows cannot be the new admin of an admin transfer.
+    /// Must be called by the owner.
+    ///
+    /// ### Arguments
+    /// * `trusted_factories` - The trusted escrow factories
+    ///
+    /// ### Panics
+    /// * `NotInitialized` - If the contract has not been initialized
+    pub fn set_trusted_factories(e: Env, trusted_factories: Vec<Address>) {
+        let (owner, mut config) = require_owner_config(&e);
+        config.trusted_factories = trusted_factories;
+        set_config(&e, owner, config);
+    }
+
+    /// Set the guardian that can cancel any admin transfer. Must be called by the owner.
+    ///
+    /// ### Arguments
+    /// * `guardian` - The new guardian
+    ///
+    /// ### Panics
+    /// * `NotInitialized` - If the contract has not been initialized
+    pub fn set_guardian(e: Env, guardian: Address) {
+        let (owner, mut config) = require_owner_config(&e);
+        config.guardian = guardian;
+        set_config(&e, owner, config);
+    }
+
+    /// Get the version of the contract and the features it supports
+    pub fn version(e: Env) -> VersionInfo {
+        version::version_info(&e)
+    }
+
+    /// Add an address to the approved new admins. Must be called by the owner. Does nothing if
+    /// the address is already approved.
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The address to approve as a new admin
+    ///
+    /// ### Panics
+    /// * `NotInitialized` - If the contract has not been initialized
+    pub fn add_approved_admin(e: Env, new_admin: Address) {
+        let owner = require_owner(&e);
+        storage::extend_instance(&e);
+
+        let mut approved_admins = storage::get_approved_admins(&e);
+        if !approved_admins.contains(&new_admin) {
+            approved_admins.push_back(new_admin.clone());
+            storage::set_approved_admins(&e, &approved_admins);
+
+            AdminTransferEvents::add_approved_admin(&e, owner, new_admin);
+        }
+    }
+
+    /// Remove an address from the approved new admins. Must be called by the owner. Does nothing
+    /// if the address is not approved.
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The address to remove from the approved new admins
+    ///
+    /// ### Panics
+    /// * `NotInitialized` - If the contract has not been initialized
+    pub fn remove_approved_admin(e: Env, new_admin: Address) {
+        let owner = require_owner(&e);
+        storage::extend_instance(&e);
+
+        let mut approved_admins = storage::get_approved_admins(&e);
+        if let Some(index) = approved_admins.first_index_of(&new_admin) {
+            approved_admins.remove(index);
+            storage::set_approved_admins(&e, &approved_admins);
+
+            AdminTransferEvents::remove_approved_admin(&e, owner, new_admin);
+        }
+    }
+
+    /// Get the list of approved new admins
+    pub fn get_approved_admins(e: Env) -> Vec<Address> {
+        storage::get_approved_admins(&e)
+    }
+
+    /// Add a WASM hash to the approved WASM hashes new admin contracts can be deployed from.
+    /// Must be called by the owner. Does nothing if the WASM hash is already approved.
+    ///
+    /// ### Arguments
+    /// * `wasm_hash` - The WASM hash to approve
+    ///
+    /// ### Panics
+    /// * `NotInitialized` - If the contract has not been initialized
+    pub fn add_approved_wasm(e: Env, wasm_hash: BytesN<32>) {
+        let owner = require_owner(&e);
+        storage::extend_instance(&e);
+
+        let mut approved_wasms = storage::get_approved_wasms(&e);
+        if !approved_wasms.contains(&wasm_hash) {
+            approved_wasms.push_back(wasm_hash.clone());
+            storage::set_approved_wasms(&e, &approved_wasms);
+
+            AdminTransferEvents::add_approved_wasm(&e, owner, wasm_hash);
+        }
+    }
+
+    /// Remove a WASM hash from the approved WASM hashes. Must be called by the owner. Contracts
+    /// deployed from the WASM hash are no longer verified new admins. Does nothing if the WASM
+    /// hash is not approved.
+    ///
+    /// ### Arguments
+    /// * `wasm_hash` - The WASM hash to remove
+    ///
+    /// ### Panics
+    /// * `NotInitialized` - If the contract has not been initialized
+    pub fn remove_approved_wasm(e: Env, wasm_hash: BytesN<32>) {
+        let owner = require_owner(&e);
+        storage::extend_instance(&e);
+
+        let mut approved_wasms = storage::get_approved_wasms(&e);
+        if let Some(index) = approved_wasms.first_index_of(&wasm_hash) {
+            approved_wasms.remove(index);
+            storage::set_approved_wasms(&e, &approved_wasms);
+
+            AdminTransferEvents::remove_approved_wasm(&e, owner, wasm_hash);
+        }
+    }
+
+    /// Get the list of approved WASM hashes
+    pub fn get_approved_wasms(e: Env) -> Vec<BytesN<32>> {
+        storage::get_approved_wasms(&e)
+    }
+
+    /// Get the WASM hash a contract was deployed from by `deploy_and_set_admin_transfer`, or
+    /// None if it was not deployed by this contract
+    ///
+    /// ### Arguments
+    /// * `address` - The address of the contract
+    pub fn get_deployment(e: Env, address: Address) -> Option<BytesN<32>> {
+        storage::get_deployment(&e, &address)
+    }
+
+    /// Set the global policy contract consulted for admin transfers without their own policy.
+    /// Must be called by the owner.
+    ///
+    /// ### Arguments
+    /// * `policy` - The policy contract, or None to remove the global policy
+    ///
+    /// ### Panics
+    /// * `NotInitialized` - If the contract has not been initialized
+    pub fn set_policy(e: Env, policy: Option<Address>) {
+        let owner = require_owner(&e);
+        storage::extend_instance(&e);
+
+        storage::set_policy(&e, &policy);
+
+        AdminTransferEvents::set_policy(&e, owner, policy);
+    }
+
+    /// Get the global policy contract
+    pub fn get_policy(e: Env) -> Option<Address> {
+        storage::get_policy(&e)
+    }
+
+    /// Set the pool health checks required to complete an admin transfer. Must be called by the owner.
+    ///
+    /// ### Arguments
+    /// * `config` - The pool health checks, or None to disable them
+    ///
+    /// ### Panics
+    /// * `NotInitialized` - If the contract has not been initialized
+    pub fn set_health_config(e: Env, config: Option<HealthConfig>) {
+        let owner = require_owner(&e);
+        storage::extend_instance(&e);
+
+        storage::set_health_config(&e, &config);
+
+        AdminTransferEvents::set_health_config(&e, owner, config);
+    }
+
+    /// Get the pool health checks required to complete an admin transfer
+    pub fn get_health_config(e: Env) -> Option<HealthConfig> {
+        storage::get_health_config(&e)
+    }
+
+    /// Set the bond the current admin posts when an admin transfer is created. Pending admin
+    /// transfers keep the bond they were created with. Must be called by the owner.
+    ///
+    /// ### Arguments
+    /// * `config` - The bond, or None to disable bonds
+    ///
+    /// ### Panics
+    /// * `NotInitialized` - If the contract has not been initialized
+    /// * `InvalidBondConfig` - If the amount is negative or the forfeit exceeds 100%
+    pub fn set_bond_config(e: Env, config: Option<BondConfig>) {
+        let owner = require_owner(&e);
+        if let Some(config) = &config {
+            assert_with_error!(
+                &e,
+                config.amount >= 0 && config.forfeit_bps <= bond::MAX_BPS,
+                ContractError::InvalidBondConfig
+            );
+        }
+        storage::extend_instance(&e);
+
+        storage::set_bond_config(&e, &config);
+
+        AdminTransferEvents::set_bond_config(&e, owner, config);
+    }
+
+    /// Get the bond the current admin posts when an admin transfer is created
+    pub fn get_bond_config(e: Env) -> Option<BondConfig> {
+        storage::get_bond_config(&e)
+    }
+
+    /// Get the bond held for an admin transfer
+    ///
+    /// ### Arguments
+    /// * `pool` - The address of the pool the admin transfer is for
+    pub fn get_bond(e: Env, pool: Address) -> Option<Bond> {
+        storage::get_bond(&e, &pool)
+    }
+
+    /// Get the amount of bonds held, returned and forfeited in a token
+    ///
+    /// ### Arguments
+    /// * `token` - The address of the bond token
+    pub fn get_bond_totals(e: Env, token: Address) -> BondTotals {
+        storage::get_bond_totals(&e, &token)
+    }
+
+    /// Preview the pool health checks for an admin transfer. Returns every failed check, or
+    /// an empty list if the transfer would not be blocked by the health checks. The pool status
+    /// the admin transfer leaves the pool in when it completes, including any `SetStatus` pool
+    /// actions, is checked.
+    ///
+    /// ### Arguments
+    /// * `pool` - The address of the pool
+    ///
+    /// ### Panics
+    /// * `NoAdminTransferExists` - If no admin transfer exists for the pool
+    pub fn preview_transfer(e: Env, pool: Address) -> Vec<HealthFailure> {
+        let admin_transfer = match storage::get_admin_transfer(&e, &pool) {
+            Some(admin_transfer) => admin_transfer,
+            None => panic_with_error!(&e, ContractError::NoAdminTransferExists),
+        };
+        match storage::get_health_config(&e) {
+            Some(config) => {
+                health::check_pool_health(&e, &pool, &config, completion_status(&admin_transfer))
+            }
+            None => Vec::new(&e),
+        }
+    }
+
     /// Set the details for an admin transfer. Also sets the admin of the pool to this contract.
-    /// Must be called by the current admin of the pool.
+    /// Must be called by the current admin of the pool. The pool can be any `TargetKind`.
     ///
     /// ### Arguments
     /// * `pool` - The address of the pool the admin transfer is for
     /// * `cur_admin` - The current admin of the pool
     /// * `new_admin` - The new admin of the pool
+    /// * `options` - The options for the admin transfer
     ///
     /// ### Panics
-    /// * `AdminTransferExists` - If the contract has already been initialized
-    pub fn set_admin_transfer(e: Env, pool: Address, cur_admin: Address, new_admin: Address) {
+    /// * `AdminTransferExists` - If an admin transfer already exists for the pool
+    /// * `PoolInCooldown` - If the pool is in cooldown from a completed admin transfer
+    /// * `NewAdminIs*` / `NewAdminNot*` - If the new admin is invalid
+    /// * `TransferRejectedByPolicy` - If the policy contract rejects the transfer
+    /// * `QueuedReserveUpdate` - If a reserve update is queued and the transfer refuses them
+    /// * `UnsupportedTargetOption` - If a Blend pool only option is used for another target
+    pub fn set_admin_transfer(
+        e: Env,
+        pool: Address,
+        cur_admin: Address,
+        new_admin: Address,
+        options: TransferOptions,
+    ) {
+        validate_admin_transfer(&e, &pool, &cur_admin, &new_admin, &options);
         assert_with_error!(
             &e,
-            !storage::has_admin_transfer(&e, &pool),
-            ContractError::AdminTransferExists
+            storage::get_approved_admins(&e).contains(&new_admin),
+            ContractError::NewAdminNotApproved
         );
         cur_admin.require_auth();
-        storage::extend_instance(&e);
 
-        let pool_client = Client::new(&e, &pool);
-        pool_client.set_admin(&e.current_contract_address());
+        create_admin_transfer(&e, pool, cur_admin, NewAdmin::Address(new_admin), options);
+    }
 
-        let admin_transfer = TransferData {
-            cur_admin,
-            new_admin,
+    /// Set the details for an admin transfer to a committed new admin. Only the SHA-256 hash of
+    /// the XDR encoded new admin followed by a salt is stored until `reveal_new_admin` is called.
+    /// Otherwise the same as `set_admin_transfer`.
+    ///
+    /// ### Arguments
+    /// * `pool` - The address of the pool the admin transfer is for
+    /// * `cur_admin` - The current admin of the pool
+    /// * `commitment` - The SHA-256 hash of the new admin and salt
+    /// * `options` - The options for the admin transfer
+    ///
+    /// ### Panics
+    /// * Any panic of `set_admin_transfer` except `NewAdminIs*` / `NewAdminNot*`
+    pub fn set_committed_admin_transfer(
+        e: Env,
+        pool: Address,
+        cur_admin: Address,
+        commitment: BytesN<32>,
+        options: TransferOptions,
+    ) {
+        validate_transfer_options(&e, &pool, &options);
+        cur_admin.require_auth();
+
+        let new_admin =
+            NewAdmin::Committed(commitment, options.allow_unverified, options.allow_eoa);
+        create_admin_transfer(&e, pool, cur_admin, new_admin, options);
+    }
+
+    /// Reveal the committed new admin of an admin transfer. Can be called by anyone who knows
+    /// the new admin and salt. The new admin is validated as in `set_admin_transfer`, and the
+    /// policy contract is consulted for the revealed transfer.
+    ///
+    /// ### Arguments
+    /// * `pool` - The address of the pool the admin transfer is for
+    /// * `new_admin` - The committed new admin of the pool
+    /// * `salt` - The salt of the commitment
+    ///
+    /// ### Panics
+    /// * `NoAdminTransferExists` - If no admin transfer exists for the pool
+    /// * `NewAdminNotCommitted` - If the admin transfer has no unrevealed commitment
+    /// * `InvalidCommitment` - If the new admin and salt do not match the commitment
+    /// * `NewAdminIs*` / `NewAdminNot*` - If the new admin is invalid
+    /// * `InvalidProposalBinding` - If the bound proposal is invalid for the new admin
+    /// * `TransferRejectedByPolicy` - If the policy contract rejects the transfer
+    pub fn reveal_new_admin(e: Env, pool: Address, new_admin: Address, salt: BytesN<32>) {
+        let mut admin_transfer = match storage::get_admin_transfer(&e, &pool) {
+            Some(admin_transfer) => admin_transfer,
+            None => panic_with_error!(&e, ContractError::NoAdminTransferExists),
         };
+        require_migrated(&e);
+        let (hash, allow_unverified, allow_eoa) = match &admin_transfer.new_admin {
+            NewAdmin::Committed(hash, allow_unverified, allow_eoa) => {
+                (hash.clone(), *allow_unverified, *allow_eoa)
+            }
+            _ => panic_with_error!(&e, ContractError::NewAdminNotCommitted),
+        };
+        let mut preimage = new_admin.clone().to_xdr(&e);
+        preimage.append(&salt.into());
+        assert_with_error!(
+            &e,
+            e.crypto().sha256(&preimage) == hash,
+            ContractError::InvalidCommitment
+        );
+        validate_new_admin(
+            &e,
+            &pool,
+            &admin_transfer.cur_admin,
+            &new_admin,
+            allow_unverified,
+            allow_eoa,
+        );
+        validate_proposal_binding(
+            &e,
+            &pool,
+            &new_admin,
+            &admin_transfer.proposal,
+            admin_transfer.bind_proposal,
+        );
+        assert_with_error!(
+            &e,
+            storage::get_approved_admins(&e).contains(&new_admin),
+            ContractError::NewAdminNotApproved
+        );
+        storage::extend_instance(&e);
+
+        admin_transfer.new_admin = NewAdmin::Address(new_admin.clone());
+        check_policy(&e, &pool, &admin_transfer, &new_admin, TransferAction::Set);
         storage::set_admin_transfer(&e, &pool, &admin_transfer);
+
+        AdminTransferEvents::reveal_new_admin(&e, pool, new_admin);
+    }
+
+    /// Set the details for an admin transfer to one of several candidate new admins. The first
+    /// candidate to call `transfer_admin_to_candidate` becomes the new admin. Otherwise the same
+    /// as `set_admin_transfer`.
+    ///
+    /// ### Arguments
+    /// * `pool` - The address of the pool the admin transfer is for
+    /// * `cur_admin` - The current admin of the pool
+    /// * `candidates` - The candidate new admins of the pool
+    /// * `options` - The options for the admin transfer
+    ///
+    /// ### Panics
+    /// * `InvalidCandidates` - If there are no candidates, more than `MAX_CANDIDATES` candidates,
+    ///   duplicate candidates, or the transfer is hashlocked
+    /// * `InvalidProposalBinding` - If the transfer is bound to a proposal
+    /// * Any panic of `set_admin_transfer` for each candidate as the new admin
+    pub fn set_candidates_admin_transfer(
+        e: Env,
+        pool: Address,
+        cur_admin: Address,
+        candidates: Vec<Address>,
+        options: TransferOptions,
+    ) {
+        validate_transfer_options(&e, &pool, &options);
+        assert_with_error!(
+            &e,
+            !candidates.is_empty()
+                && candidates.len() <= storage::MAX_CANDIDATES
+                && options.hashlock == Hashlock::None,
+            ContractError::InvalidCandidates
+        );
+        assert_with_error!(
+            &e,
+            !options.bind_proposal,
+            ContractError::InvalidProposalBinding
+        );
+        let approved_admins = storage::get_approved_admins(&e);
+        for (index, candidate) in candidates.iter().enumerate() {
+            assert_with_error!(
+                &e,
+                candidates.first_index_of(&candidate) == Some(index as u32),
+                ContractError::InvalidCandidates
+            );
+            validate_new_admin(
+                &e,
+                &pool,
+                &cur_admin,
+                &candidate,
+                options.allow_unverified,
+                options.allow_eoa,
+            );
+            assert_with_error!(
+                &e,
+                approved_admins.contains(&candidate),
+                ContractError::NewAdminNotApproved
+            );
+        }
+        cur_admin.require_auth();
+
+        let new_admin = NewAdmin::Candidates(candidates);
+        create_admin_transfer(&e, pool, cur_admin, new_admin, options);
+    }
+
+    /// Deploy a new admin contract from an approved WASM hash and set the details for an admin
+    /// transfer to it. Must be called by the current admin of the pool. The deployed contract
+    /// does not need to be an approved new admin. The contract is deployed with the SHA-256 hash
+    /// of the XDR encoded current admin and pool followed by the salt, so another caller cannot
+    /// deploy to the same address first.
+    ///
+    /// Returns the address of the deployed contract
+    ///
+    /// ### Arguments
+    /// * `pool` - The address of the pool the admin transfer is for
+    /// * `cur_admin` - The current admin of the pool
+    /// * `deployment` - The deployment details of the new admin contract
+    /// * `options` - The options for the admin transfer
+    ///
+    /// ### Panics
+    /// * `WasmNotApproved` - If the WASM hash is not an approved WASM hash
+    /// * Any panic of `set_admin_transfer` except `NewAdminNotApproved`
+    pub fn deploy_and_set_admin_transfer(
+        e: Env,
+        pool: Address,
+        cur_admin: Address,
+        deployment: Deployment,
+        options: TransferOptions,
+    ) -> Address {
+        assert_with_error!(
+            &e,
+            storage::get_approved_wasms(&e).contains(&deployment.wasm_hash),
+            ContractError::WasmNotApproved
+        );
+        cur_admin.require_auth();
+
+        let mut salt = cur_admin.clone().to_xdr(&e);
+        salt.append(&pool.clone().to_xdr(&e));
+        salt.append(&deployment.salt.into());
+        let new_admin = e
+            .deployer()
+            .with_current_contract(e.crypto().sha256(&salt))
+            .deploy(deployment.wasm_hash.clone());
+        e.invoke_contract::<Val>(&new_admin, &deployment.init_fn, deployment.init_args);
+        storage::set_deployment(&e, &new_admin, &deployment.wasm_hash);
+
+        validate_admin_transfer(&e, &pool, &cur_admin, &new_admin, &options);
+        create_admin_transfer(
+            &e,
+            pool,
+            cur_admin,
+            NewAdmin::Address(new_admin.clone()),
+            options,
+        );
+        new_admin
     }
 
     /// Get the new admin for an admin transfer
@@ -48,42 +712,777 @@ impl AdminTransfer {
         storage::get_admin_transfer(&e, &pool)
     }
 
-    /// Transfer the admin of a pool from the current admin to the new admin
+    /// Transfer the admin of a pool to the new admin. Must be called by the new admin, which for a
+    /// transfer bound to a proposal is the governor executing the proposal. Pool admin actions are
+    /// executed before the new admin is set. If enabled and the new admin is a contract, `on_pool_admin_received` is invoked on it.
     ///
     /// ### Arguments
     /// * `pool` - The address of the pool the admin transfer is for
     ///
     /// ### Panics
     /// * `NoAdminTransferExists` - If no admin transfer exists for the pool
+    /// * `AdminTransferLocked` / `AdminTransferExpired` - If the transfer is locked or expired
+    /// * `NewAdminNotRevealed` - If the committed new admin has not been revealed
+    /// * `CandidateRequired` - If the admin transfer has candidate new admins
+    /// * `PreimageRequired` - If the admin transfer is hashlocked
+    /// * `TransferRejectedByPolicy` - If the policy contract rejects the admin transfer
+    /// * `PoolHealthCheckFailed` - If the pool fails any pool health check
+    /// * `QueuedReserveUpdate` - If a reserve update is queued and refused by the transfer
     pub fn transfer_admin(e: Env, pool: Address) {
+        complete_admin_transfer(&e, pool, None, None);
+    }
+
+    /// Transfer the admin of a pool to one of the candidate new admins of the admin transfer.
+    /// Must be called by the candidate. The first candidate to call this becomes the new admin,
+    /// and every other candidate is notified with a `reject_candidate` event. Otherwise the same
+    /// as `transfer_admin`.
+    ///
+    /// ### Arguments
+    /// * `pool` - The address of the pool the admin transfer is for
+    /// * `candidate` - The candidate new admin accepting the pool
+    ///
+    /// ### Panics
+    /// * `NotACandidate` - If the address is not a candidate new admin of the admin transfer
+    /// * Any panic of `transfer_admin` except `CandidateRequired`
+    pub fn transfer_admin_to_candidate(e: Env, pool: Address, candidate: Address) {
+        complete_admin_transfer(&e, pool, Some(candidate), None);
+    }
+
+    /// Transfer the admin of a hashlocked admin transfer. The preimage of the hashlock is
+    /// published in the completion event. Otherwise the same as `transfer_admin`.
+    ///
+    /// ### Arguments
+    /// * `pool` - The address of the pool the admin transfer is for
+    /// * `preimage` - The preimage of the SHA-256 hashlock
+    ///
+    /// ### Panics
+    /// * `InvalidPreimage` - If the admin transfer is not hashlocked or the preimage does not match
+    /// * Any panic of `transfer_admin` except `PreimageRequired`
+    pub fn transfer_admin_with_preimage(e: Env, pool: Address, preimage: Bytes) {
+        complete_admin_transfer(&e, pool, None, Some(preimage));
+    }
+
+    /// Cancel an admin transfer. Must be called by the creator of the admin transfer. Expired
+    /// admin transfers can be cancelled. If the pool status was set when escrow began, the
+    /// pre-escrow status is restored if the pool allows it. Part of any bond is forfeited. The
+    /// policy contract is consulted for each candidate new admin, and is not consulted for an
+    /// unrevealed new admin.
+    ///
+    /// ### Arguments
+    /// * `pool` - The address of the pool the admin transfer is for
+    ///
+    /// ### Panics
+    /// * `NoAdminTransferExists` - If no admin transfer exists for the pool
+    /// * `TransferRejectedByPolicy` - If the policy contract rejects the cancellation
+    pub fn cancel_admin_transfer(e: Env, pool: Address) {
         let admin_transfer = match storage::get_admin_transfer(&e, &pool) {
             Some(admin) => admin,
             None => panic_with_error!(&e, ContractError::NoAdminTransferExists),
         };
-        admin_transfer.new_admin.require_auth();
-        storage::extend_instance(&e);
+        require_migrated(&e);
+        admin_transfer.cur_admin.require_auth();
 
-        let pool_client = Client::new(&e, &pool);
-        pool_client.set_admin(&admin_transfer.new_admin);
+        check_policy_each(&e, &pool, &admin_transfer, TransferAction::Cancel);
 
-        storage::del_admin_transfer(&e, &pool);
+        return_admin(&e, &pool, &admin_transfer, false);
+        bond::settle_bond(&e, &pool, &admin_transfer.cur_admin, true);
     }
 
-    /// Cancel an admin transfer. Must be called by the creator of the admin transfer.
+    /// Cancel an admin transfer in an emergency. Must be called by the guardian. The admin of
+    /// the pool and any bond are returned to the current admin without consulting the policy
+    /// contract. For two-step targets, the current admin is only proposed and must accept the
+    /// handover on the target.
     ///
     /// ### Arguments
     /// * `pool` - The address of the pool the admin transfer is for
-    pub fn cancel_admin_transfer(e: Env, pool: Address) {
+    ///
+    /// ### Panics
+    /// * `NotInitialized` - If the contract has not been initialized
+    /// * `NoAdminTransferExists` - If no admin transfer exists for the pool
+    pub fn guardian_cancel_admin_transfer(e: Env, pool: Address) {
+        let config = match storage::get_config(&e) {
+            Some(config) => config,
+            None => panic_with_error!(&e, ContractError::NotInitialized),
+        };
         let admin_transfer = match storage::get_admin_transfer(&e, &pool) {
             Some(admin) => admin,
             None => panic_with_error!(&e, ContractError::NoAdminTransferExists),
         };
-        admin_transfer.cur_admin.require_auth();
+        require_migrated(&e);
+        config.guardian.require_auth();
+
+        // the guardian cannot authorize the acceptance of a two-step handover on behalf of the
+        // current admin, so it is left pending for the current admin to accept
+        return_admin(&e, &pool, &admin_transfer, true);
+        bond::settle_bond(&e, &pool, &admin_transfer.cur_admin, false);
+
+        AdminTransferEvents::guardian_cancel(&e, config.guardian, pool);
+    }
+
+    /// Queue an upgrade of the contract to a new WASM hash. The upgrade can be executed once
+    /// `UPGRADE_DELAY_LEDGERS` have passed. Replaces any queued upgrade. Must be called by the owner.
+    ///
+    /// ### Arguments
+    /// * `wasm_hash` - The WASM hash to upgrade the contract to
+    ///
+    /// ### Panics
+    /// * `NotInitialized` - If the contract has not been initialized
+    pub fn queue_upgrade(e: Env, wasm_hash: BytesN<32>) {
+        let owner = require_owner(&e);
+        storage::extend_instance(&e);
+
+        let upgrade = QueuedUpgrade {
+            wasm_hash,
+            unlock_ledger: e
+                .ledger()
+                .sequence()
+                .saturating_add(storage::UPGRADE_DELAY_LEDGERS),
+        };
+        storage::set_queued_upgrade(&e, &Some(upgrade.clone()));
+
+        AdminTransferEvents::queue_upgrade(&e, owner, upgrade);
+    }
+
+    /// Cancel the queued upgrade. Must be called by the owner.
+    ///
+    /// ### Panics
+    /// * `NotInitialized` - If the contract has not been initialized
+    /// * `NoQueuedUpgrade` - If no upgrade is queued
+    pub fn cancel_upgrade(e: Env) {
+        let owner = require_owner(&e);
+        let upgrade = match storage::get_queued_upgrade(&e) {
+            Some(upgrade) => upgrade,
+            None => panic_with_error!(&e, ContractError::NoQueuedUpgrade),
+        };
+        storage::extend_instance(&e);
+
+        storage::set_queued_upgrade(&e, &None);
+
+        AdminTransferEvents::cancel_upgrade(&e, owner, upgrade.wasm_hash);
+    }
 
-        let pool_client = Client::new(&e, &pool);
-        pool_client.set_admin(&admin_transfer.cur_admin);
+    /// Get the queued upgrade
+    pub fn get_queued_upgrade(e: Env) -> Option<QueuedUpgrade> {
+        storage::get_queued_upgrade(&e)
+    }
+
+    /// Execute the queued upgrade. Must be called by the owner.
+    ///
+    /// The stored data keeps its storage layout version. If the new WASM uses a different storage
+    /// layout, it refuses to use the stored data until it is migrated, and cannot be upgraded
+    /// again before then.
+    ///
+    /// ### Panics
+    /// * `NotInitialized` - If the contract has not been initialized
+    /// * `NoQueuedUpgrade` - If no upgrade is queued
+    /// * `UpgradeLocked` - If the upgrade delay has not passed
+    /// * `MigrationRequired` - If the stored data has not been migrated to the current layout
+    pub fn upgrade(e: Env) {
+        let owner = require_owner(&e);
+        let upgrade = match storage::get_queued_upgrade(&e) {
+            Some(upgrade) => upgrade,
+            None => panic_with_error!(&e, ContractError::NoQueuedUpgrade),
+        };
+        assert_with_error!(
+            &e,
+            e.ledger().sequence() >= upgrade.unlock_ledger,
+            ContractError::UpgradeLocked
+        );
+        require_migrated(&e);
+        storage::extend_instance(&e);
+
+        storage::set_queued_upgrade(&e, &None);
+        e.deployer()
+            .update_current_contract_wasm(upgrade.wasm_hash.clone());
+
+        AdminTransferEvents::upgrade(&e, owner, upgrade.wasm_hash);
+    }
 
+    /// Migrate the pending admin transfers of the given pools to the storage layout of this
+    /// contract. Can be called by anyone once the contract has been initialized.
+    ///
+    /// A pool without an admin transfer to migrate is skipped. Until its admin transfer is
+    /// migrated, a pool cannot be used in any admin transfer.
+    ///
+    /// ### Arguments
+    /// * `pools` - The addresses of the pools with a pending admin transfer
+    ///
+    /// ### Panics
+    /// * `MigrationRequired` - If the stored data has not been migrated to the current layout
+    pub fn migrate_admin_transfers(e: Env, pools: Vec<Address>) {
+        require_migrated(&e);
         storage::extend_instance(&e);
-        storage::del_admin_transfer(&e, &pool);
+
+        for pool in pools.iter() {
+            if storage::migrate_admin_transfer(&e, &pool) {
+                AdminTransferEvents::migrate_admin_transfer(&e, pool);
+            }
+        }
+    }
+}
+
+/// Complete an admin transfer and set the admin of the pool to the new admin
+///
+/// ### Arguments
+/// * `pool` - The address of the pool the admin transfer is for
+/// * `candidate` - The candidate new admin accepting the pool, if the transfer has candidates
+/// * `preimage` - The preimage of the hashlock, if the admin transfer is hashlocked
+fn complete_admin_transfer(
+    e: &Env,
+    pool: Address,
+    candidate: Option<Address>,
+    preimage: Option<Bytes>,
+) {
+    let admin_transfer = match storage::get_admin_transfer(e, &pool) {
+        Some(admin) => admin,
+        None => panic_with_error!(e, ContractError::NoAdminTransferExists),
+    };
+    require_migrated(e);
+    assert_with_error!(
+        e,
+        e.ledger().sequence() >= admin_transfer.unlock_ledger,
+        ContractError::AdminTransferLocked
+    );
+    assert_with_error!(
+        e,
+        e.ledger().sequence() < admin_transfer.expiration_ledger,
+        ContractError::AdminTransferExpired
+    );
+    let new_admin = match (&admin_transfer.new_admin, candidate) {
+        (NewAdmin::Committed(..), _) => panic_with_error!(e, ContractError::NewAdminNotRevealed),
+        (NewAdmin::Address(new_admin), None) => new_admin.clone(),
+        (NewAdmin::Candidates(_), None) => panic_with_error!(e, ContractError::CandidateRequired),
+        (NewAdmin::Candidates(candidates), Some(candidate)) => {
+            assert_with_error!(
+                e,
+                candidates.contains(&candidate),
+                ContractError::NotACandidate
+            );
+            candidate
+        }
+        (NewAdmin::Address(_), Some(_)) => panic_with_error!(e, ContractError::NotACandidate),
+    };
+    new_admin.require_auth();
+    match (&admin_transfer.hashlock, &preimage) {
+        (Hashlock::None, None) => {}
+        (Hashlock::Sha256(hash), Some(preimage)) => assert_with_error!(
+            e,
+            e.crypto().sha256(preimage) == *hash,
+            ContractError::InvalidPreimage
+        ),
+        (Hashlock::Sha256(_), None) => panic_with_error!(e, ContractError::PreimageRequired),
+        (Hashlock::None, Some(_)) => panic_with_error!(e, ContractError::InvalidPreimage),
+    }
+    storage::extend_instance(e);
+
+    check_policy(
+        e,
+        &pool,
+        &admin_transfer,
+        &new_admin,
+        TransferAction::Transfer,
+    );
+
+    let pool_client = Client::new(e, &pool);
+    if let EscrowStatus::Set(_, status) = admin_transfer.escrow_status {
+        pool_client.set_status(&status);
+    }
+    reserves::handle_queued_reserves(e, &pool, &admin_transfer.queued_reserves);
+    actions::execute_pool_actions(e, &pool, &admin_transfer.actions);
+
+    // the pool actions can change the pool, so the pool is checked as it is handed over
+    if admin_transfer.kind == TargetKind::BlendPool {
+        if let Some(config) = storage::get_health_config(e) {
+            assert_with_error!(
+                e,
+                health::check_pool_health(e, &pool, &config, completion_status(&admin_transfer))
+                    .is_empty(),
+                ContractError::PoolHealthCheckFailed
+            );
+        }
+    }
+
+    adapter::set_admin(e, &pool, &admin_transfer.kind, &new_admin);
+
+    storage::del_admin_transfer(e, &pool);
+    bond::settle_bond(e, &pool, &admin_transfer.cur_admin, false);
+    let config = storage::get_config(e).unwrap_optimized();
+    if config.cooldown > 0 {
+        storage::set_cooldown(
+            e,
+            &pool,
+            e.ledger().sequence().saturating_add(config.cooldown),
+        );
+    }
+
+    AdminTransferEvents::transfer_admin(
+        e,
+        pool.clone(),
+        &admin_transfer,
+        new_admin.clone(),
+        preimage,
+    );
+    if let NewAdmin::Candidates(candidates) = &admin_transfer.new_admin {
+        for candidate in candidates.iter() {
+            if candidate != new_admin {
+                AdminTransferEvents::reject_candidate(
+                    e,
+                    candidate,
+                    pool.clone(),
+                    new_admin.clone(),
+                );
+            }
+        }
+    }
+
+    if admin_transfer.receive_hook != ReceiveHook::Disabled && is_contract(&new_admin) {
+        let receiver_client = AdminReceiverClient::new(e, &new_admin);
+        if admin_transfer.receive_hook == ReceiveHook::Fatal {
+            receiver_client.on_pool_admin_received(&pool, &admin_transfer.cur_admin);
+        } else {
+            let _ = receiver_client.try_on_pool_admin_received(&pool, &admin_transfer.cur_admin);
+        }
+    }
+}
+
+/// Validate the details of a new admin transfer
+///
+/// ### Panics
+/// * Any panic of `validate_transfer_options`, `validate_new_admin` or
+///   `validate_proposal_binding`
+fn validate_admin_transfer(
+    e: &Env,
+    pool: &Address,
+    cur_admin: &Address,
+    new_admin: &Address,
+    options: &TransferOptions,
+) {
+    validate_transfer_options(e, pool, options);
+    validate_new_admin(
+        e,
+        pool,
+        cur_admin,
+        new_admin,
+        options.allow_unverified,
+        options.allow_eoa,
+    );
+    validate_proposal_binding(e, pool, new_admin, &options.proposal, options.bind_proposal);
+}
+
+/// Validate that an admin transfer can be created for a pool with the given options
+///
+/// ### Panics
+/// * `MigrationRequired` - If the stored data or the admin transfer of the pool has not been
+///   migrated to the current layout
+/// * `NotInitialized` - If the contract has not been initialized
+/// * `UnsupportedPool` - If the contract is an escrow for a different pool
+/// * `AdminTransferExists` - If an admin transfer already exists for the pool
+/// * `PoolInCooldown` - If the pool is in cooldown from a completed admin transfer
+/// * `MemoTooLong` - If the memo is longer than `MAX_MEMO_LENGTH` bytes
+/// * `UnsupportedTargetOption` - If a Blend pool only option is used for another target
+fn validate_transfer_options(e: &Env, pool: &Address, options: &TransferOptions) {
+    require_migrated(e);
+    assert_with_error!(
+        e,
+        !storage::has_legacy_admin_transfer(e, pool),
+        ContractError::MigrationRequired
+    );
+    assert_with_error!(
+        e,
+        storage::get_config(e).is_some(),
+        ContractError::NotInitialized
+    );
+    if let Some(escrow_pool) = storage::get_pool(e) {
+        assert_with_error!(e, *pool == escrow_pool, ContractError::UnsupportedPool);
+    }
+    assert_with_error!(
+        e,
+        !storage::has_admin_transfer(e, pool),
+        ContractError::AdminTransferExists
+    );
+    assert_with_error!(
+        e,
+        e.ledger().sequence() >= storage::get_cooldown(e, pool),
+        ContractError::PoolInCooldown
+    );
+    assert_with_error!(
+        e,
+        options.memo.len() <= storage::MAX_MEMO_LENGTH,
+        ContractError::MemoTooLong
+    );
+    assert_with_error!(
+        e,
+        options.kind == TargetKind::BlendPool
+            || (options.queued_reserves == QueuedReserveMode::Ignore
+                && options.actions.is_empty()
+                && options.escrow_status == EscrowStatus::Unchanged),
+        ContractError::UnsupportedTargetOption
+    );
+}
+
+/// Validate the new admin of an admin transfer
+///
+/// ### Panics
+/// * `NewAdminIsCurrentAdmin` - If the new admin is the current admin
+/// * `NewAdminIsEscrow` - If the new admin is this contract or an escrow of a trusted factory
+/// * `NewAdminIsPool` - If the new admin is the pool itself
+/// * `NewAdminNotVerified` - If the new admin was not deployed by `deploy_and_set_admin_transfer`
+///   from a WASM hash that is still approved, and `allow_unverified` is not set
+/// * `NewAdminNotContract` - If the new admin is unverified and not a contract, and `allow_eoa`
+///   is not set
+fn validate_new_admin(
+    e: &Env,
+    pool: &Address,
+    cur_admin: &Address,
+    new_admin: &Address,
+    allow_unverified: bool,
+    allow_eoa: bool,
+) {
+    let config = storage::get_config(e).unwrap_optimized();
+    assert_with_error!(
+        e,
+        new_admin != cur_admin,
+        ContractError::NewAdminIsCurrentAdmin
+    );
+    assert_with_error!(
+        e,
+        *new_admin != e.current_contract_address(),
+        ContractError::NewAdminIsEscrow
+    );
+    for factory in config.trusted_factories.iter() {
+        assert_with_error!(
+            e,
+            !EscrowFactoryClient::new(e, &factory).is_escrow(new_admin),
+            ContractError::NewAdminIsEscrow
+        );
+    }
+    assert_with_error!(e, new_admin != pool, ContractError::NewAdminIsPool);
+    if !is_verified(e, new_admin) {
+        assert_with_error!(e, allow_unverified, ContractError::NewAdminNotVerified);
+        assert_with_error!(
+            e,
+            allow_eoa || is_contract(new_admin),
+            ContractError::NewAdminNotContract
+        );
+    }
+}
+
+/// Validate that an admin transfer bound to a proposal references a proposal on the new admin
+/// that is open or successful and completes the admin transfer when it executes, so the new admin
+/// can only authorize the transfer by executing the bound proposal
+///
+/// ### Panics
+/// * `InvalidProposalBinding` - If the transfer is bound to a proposal not on the new admin, to a
+///   proposal that is not open or successful, or to a proposal that does not complete the transfer
+fn validate_proposal_binding(
+    e: &Env,
+    pool: &Address,
+    new_admin: &Address,
+    proposal: &ProposalReference,
+    bind_proposal: bool,
+) {
+    if bind_proposal {
+        let bound = match proposal {
+            ProposalReference::Governor(governor, proposal_id) if governor == new_admin => {
+                match GovernorClient::new(e, governor).get_proposal(proposal_id) {
+                    Some(proposal) => {
+                        matches!(
+                            proposal.data.status,
+                            ProposalStatus::Open | ProposalStatus::Successful
+                        ) && completes_admin_transfer(e, pool, &proposal.config.action)
+                    }
+                    None => false,
+                }
+            }
+            _ => false,
+        };
+        assert_with_error!(e, bound, ContractError::InvalidProposalBinding);
+    }
+}
+
+/// Check if a governor proposal action completes the admin transfer of a pool on this contract
+///
+/// ### Arguments
+/// * `pool` - The address of the pool the admin transfer is for
+/// * `action` - The action of the proposal
+fn completes_admin_transfer(e: &Env, pool: &Address, action: &Vec<Val>) -> bool {
+    match ProposalAction::try_from_val(e, action.as_val()) {
+        Ok(ProposalAction::Calldata(calldata)) => {
+            calldata.contract_id == e.current_contract_address()
+                && (calldata.function == Symbol::new(e, "transfer_admin")
+                    || calldata.function == Symbol::new(e, "transfer_admin_with_preimage"))
+                && calldata
+                    .args
+                    .first()
+                    .and_then(|arg| Address::try_from_val(e, &arg).ok())
+                    .as_ref()
+                    == Some(pool)
+        }
+        Err(_) => false,
+    }
+}
+
+/// Create an admin transfer and set the admin of the pool to this contract. The current admin
+/// must have authorized the invocation. The policy contract is consulted for each candidate new
+/// admin, and is not consulted for a committed new admin until it is revealed.
+///
+/// ### Panics
+/// * `TransferRejectedByPolicy` - If the policy contract rejects the transfer
+/// * `QueuedReserveUpdate` - If a reserve update is queued and the transfer refuses them
+/// * `InvalidExpiry` - If the expiry exceeds the maximum expiry or is not more than the timelock
+fn create_admin_transfer(
+    e: &Env,
+    pool: Address,
+    cur_admin: Address,
+    new_admin: NewAdmin,
+    options: TransferOptions,
+) {
+    storage::extend_instance(e);
+
+    let config = storage::get_config(e).unwrap_optimized();
+    let timelock = if options.timelock == 0 {
+        config.default_timelock
+    } else {
+        options.timelock
+    };
+    let expiry = if options.expiry == 0 {
+        config.max_expiry
+    } else {
+        options.expiry
+    };
+    assert_with_error!(
+        e,
+        expiry <= config.max_expiry && timelock < expiry,
+        ContractError::InvalidExpiry
+    );
+
+    let admin_transfer = TransferData {
+        kind: options.kind,
+        cur_admin,
+        new_admin,
+        receive_hook: options.receive_hook,
+        policy: options.policy,
+        queued_reserves: options.queued_reserves,
+        actions: options.actions,
+        escrow_status: options.escrow_status,
+        prev_status: options.pool_status,
+        unlock_ledger: e.ledger().sequence().saturating_add(timelock),
+        expiration_ledger: e.ledger().sequence().saturating_add(expiry),
+        memo: options.memo,
+        proposal: options.proposal,
+        bind_proposal: options.bind_proposal,
+        hashlock: options.hashlock,
+    };
+    check_policy_each(e, &pool, &admin_transfer, TransferAction::Set);
+
+    adapter::set_admin(
+        e,
+        &pool,
+        &admin_transfer.kind,
+        &e.current_contract_address(),
+    );
+    reserves::handle_queued_reserves(e, &pool, &admin_transfer.queued_reserves);
+    if let EscrowStatus::Set(status, _) = admin_transfer.escrow_status {
+        Client::new(e, &pool).set_status(&status);
+    }
+
+    storage::set_admin_transfer(e, &pool, &admin_transfer);
+    if let Some(bond_config) = storage::get_bond_config(e) {
+        bond::post_bond(e, &pool, &admin_transfer.cur_admin, bond_config);
+    }
+
+    AdminTransferEvents::set_admin_transfer(e, pool, &admin_transfer);
+}
+
+/// Return the admin of a pool to the current admin of an admin transfer and delete the transfer.
+/// If the pool status was set when escrow began, the admin status matching the pre-escrow status
+/// is restored if the pool allows it. If `propose_only` is set, a two-step handover is left
+/// pending for the current admin to accept.
+fn return_admin(e: &Env, pool: &Address, admin_transfer: &TransferData, propose_only: bool) {
+    let pool_client = Client::new(e, pool);
+    if admin_transfer.escrow_status != EscrowStatus::Unchanged {
+        // the pool may not allow the status to be restored (e.g. the backstop fell below its
+        // threshold), so a failure must not prevent the current admin from recovering the pool
+        let status = admin_status(admin_transfer.prev_status);
+        if pool_client.try_set_status(&status).is_err() {
+            AdminTransferEvents::restore_status_failed(e, pool.clone(), status);
+        }
+    }
+    if propose_only {
+        adapter::propose_admin(e, pool, &admin_transfer.kind, &admin_transfer.cur_admin);
+    } else {
+        adapter::set_admin(e, pool, &admin_transfer.kind, &admin_transfer.cur_admin);
     }
+
+    storage::extend_instance(e);
+    storage::del_admin_transfer(e, pool);
+
+    AdminTransferEvents::cancel_admin_transfer(e, pool.clone(), admin_transfer);
+}
+
+/// Get the status a pool has once an admin transfer completes. This is the status set by the
+/// last `SetStatus` pool action, or the status set by the escrow status when the transfer
+/// completes, or the status recorded when escrow began.
+///
+/// ### Arguments
+/// * `admin_transfer` - The admin transfer
+fn completion_status(admin_transfer: &TransferData) -> u32 {
+    let mut status = match admin_transfer.escrow_status {
+        EscrowStatus::Set(_, status) => status,
+        EscrowStatus::Unchanged => admin_transfer.prev_status,
+    };
+    for action in admin_transfer.actions.iter() {
+        if let PoolAction::SetStatus(action_status) = action {
+            status = action_status;
+        }
+    }
+    status
+}
+
+/// Get the admin pool status matching a pool status. An admin can only set the admin statuses
+/// (0 active, 2 on ice, 4 frozen), so the non-admin statuses map to the admin status of the same
+/// level and the setup status maps to on ice.
+///
+/// ### Arguments
+/// * `status` - The pool status
+fn admin_status(status: u32) -> u32 {
+    match status {
+        1 => 0,
+        3 | 6 => 2,
+        5 => 4,
+        status => status,
+    }
+}
+
+/// Validate the global configuration of the contract
+///
+/// ### Panics
+/// * `InvalidExpiry` - If the default timelock is not less than the maximum expiry
+fn validate_config(e: &Env, config: &Config) {
+    assert_with_error!(
+        e,
+        config.default_timelock < config.max_expiry,
+        ContractError::InvalidExpiry
+    );
+}
+
+/// Validate and store the global configuration of the contract
+fn set_config(e: &Env, owner: Address, config: Config) {
+    validate_config(e, &config);
+    storage::extend_instance(e);
+
+    storage::set_config(e, &config);
+
+    AdminTransferEvents::set_config(e, owner, config);
+}
+
+/// Require that the owner of the contract has authorized the invocation
+///
+/// Returns the owner and the global configuration
+fn require_owner_config(e: &Env) -> (Address, Config) {
+    let owner = require_owner(e);
+    match storage::get_config(e) {
+        Some(config) => (owner, config),
+        None => panic_with_error!(e, ContractError::NotInitialized),
+    }
+}
+
+/// Require that the owner of the contract has authorized the invocation
+///
+/// Returns the owner
+fn require_owner(e: &Env) -> Address {
+    let owner = match storage::get_owner(e) {
+        Some(owner) => owner,
+        None => panic_with_error!(e, ContractError::NotInitialized),
+    };
+    owner.require_auth();
+    owner
+}
+
+/// Require that the stored data uses the storage layout of this contract
+///
+/// ### Panics
+/// * `MigrationRequired` - If the stored data has not been migrated to the current layout
+fn require_migrated(e: &Env) {
+    assert_with_error!(
+        e,
+        storage::get_storage_version(e) == storage::STORAGE_VERSION,
+        ContractError::MigrationRequired
+    );
+}
+
+/// Consult the global policy contract, if one is set, and the policy contract of an admin
+/// transfer, if any, for an admin transfer to a new admin
+///
+/// ### Panics
+/// * `TransferRejectedByPolicy` - If either policy contract rejects the action
+fn check_policy(
+    e: &Env,
+    pool: &Address,
+    admin_transfer: &TransferData,
+    new_admin: &Address,
+    action: TransferAction,
+) {
+    let transfer_policy = match &admin_transfer.policy {
+        PolicySource::Contract(policy) => Some(policy.clone()),
+        PolicySource::Global => None,
+    };
+    for policy in [storage::get_policy(e), transfer_policy]
+        .into_iter()
+        .flatten()
+    {
+        let result = TransferPolicyClient::new(e, &policy).try_check_transfer(
+            pool,
+            &admin_transfer.cur_admin,
+            new_admin,
+            &action,
+        );
+        if result.is_err() {
+            panic_with_error!(e, ContractError::TransferRejectedByPolicy);
+        }
+    }
+}
+
+/// Consult the policy contract for an admin transfer to its new admin, or to each of its
+/// candidate new admins. The policy contract is not consulted for an unrevealed new admin.
+///
+/// ### Panics
+/// * `TransferRejectedByPolicy` - If the policy contract rejects the action
+fn check_policy_each(
+    e: &Env,
+    pool: &Address,
+    admin_transfer: &TransferData,
+    action: TransferAction,
+) {
+    match &admin_transfer.new_admin {
+        NewAdmin::Address(new_admin) => check_policy(e, pool, admin_transfer, new_admin, action),
+        NewAdmin::Candidates(candidates) => {
+            for candidate in candidates.iter() {
+                check_policy(e, pool, admin_transfer, &candidate, action);
+            }
+        }
+        NewAdmin::Committed(..) => {}
+    }
+}
+
+/// Check if an address is a contract deployed by `deploy_and_set_admin_transfer` from a WASM
+/// hash that is still approved
+///
+/// ### Arguments
+/// * `address` - The address to check
+fn is_verified(e: &Env, address: &Address) -> bool {
+    match storage::get_deployment(e, address) {
+        Some(wasm_hash) => storage::get_approved_wasms(e).contains(&wasm_hash),
+        None => false,
+    }
+}
+
+/// Check if an address is a contract based on the prefix of its strkey
+///
+/// ### Arguments
+/// * `address` - The address to check
+fn is_contract(address: &Address) -> bool {
+    let mut strkey = [0u8; 56];
+    address.to_string().copy_into_slice(&mut strkey);
+    strkey[0] == b'C'
 }
@@ -10,13 +10,44 @@ pub enum ContractError {
 
     UnauthorizedError = 4,
 
-    NegativeAmountError = 8,
-    AllowanceError = 9,
-    BalanceError = 10,
     OverflowError = 12,
 
     // Custom errors
     AdminTransferExists = 100,
     NoAdminTransferExists = 101,
     AdminTransferComplete = 102,
+    NewAdminIsCurrentAdmin = 103,
+    NewAdminIsEscrow = 104,
+    NewAdminIsPool = 105,
+    NewAdminNotVerified = 106,
+    NotInitialized = 107,
+    NewAdminNotApproved = 108,
+    TransferRejectedByPolicy = 109,
+    PoolHealthCheckFailed = 110,
+    QueuedReserveUpdate = 111,
+    UnsupportedTargetOption = 112,
+    WasmNotApproved = 113,
+    NoQueuedUpgrade = 114,
+    UpgradeLocked = 115,
+    MigrationRequired = 116,
+    AdminTransferLocked = 119,
+    AdminTransferExpired = 120,
+    InvalidExpiry = 121,
+    OwnerProposalExists = 122,
+    NoOwnerProposal = 123,
+    OwnerProposalExpired = 124,
+    PoolInCooldown = 125,
+    MemoTooLong = 126,
+    InvalidProposalBinding = 127,
+    InvalidBondConfig = 129,
+    PreimageRequired = 130,
+    InvalidPreimage = 131,
+    NewAdminNotCommitted = 132,
+    InvalidCommitment = 133,
+    NewAdminNotRevealed = 134,
+    InvalidCandidates = 135,
+    CandidateRequired = 136,
+    NotACandidate = 137,
+    UnsupportedPool = 138,
+    NewAdminNotContract = 139,
 }
@@ -0,0 +1,240 @@
+use soroban_sdk::{Address, Bytes, BytesN, Env, String, Symbol};
+
+use crate::{
+    bond::BondConfig,
+    health::HealthConfig,
+    storage::{Config, NewAdmin, OwnerProposal, ProposalReference, QueuedUpgrade, TransferData},
+};
+
+pub struct AdminTransferEvents {}
+
+impl AdminTransferEvents {
+    /// Emitted when an admin transfer is created
+    ///
+    /// - topics - `["set_admin_transfer", pool: Address]`
+    /// - data - `[cur_admin: Address, new_admin: Option<Address>, memo: String, proposal: ProposalReference]`
+    ///
+    /// `new_admin` is None for a committed new admin or candidate new admins
+    pub fn set_admin_transfer(e: &Env, pool: Address, admin_transfer: &TransferData) {
+        let topics = (Symbol::new(e, "set_admin_transfer"), pool);
+        e.events()
+            .publish(topics, Self::transfer_details(admin_transfer));
+    }
+
+    /// Emitted when an admin transfer completes
+    ///
+    /// - topics - `["transfer_admin", pool: Address]`
+    /// - data - `[cur_admin: Address, new_admin: Address, memo: String, proposal: ProposalReference, preimage: Option<Bytes>]`
+    pub fn transfer_admin(
+        e: &Env,
+        pool: Address,
+        admin_transfer: &TransferData,
+        new_admin: Address,
+        preimage: Option<Bytes>,
+    ) {
+        let topics = (Symbol::new(e, "transfer_admin"), pool);
+        let (cur_admin, _, memo, proposal) = Self::transfer_details(admin_transfer);
+        e.events()
+            .publish(topics, (cur_admin, new_admin, memo, proposal, preimage));
+    }
+
+    /// Emitted when an admin transfer is cancelled
+    ///
+    /// - topics - `["cancel_admin_transfer", pool: Address]`
+    /// - data - `[cur_admin: Address, new_admin: Option<Address>, memo: String, proposal: ProposalReference]`
+    ///
+    /// `new_admin` is None for an unrevealed new admin or candidate new admins
+    pub fn cancel_admin_transfer(e: &Env, pool: Address, admin_transfer: &TransferData) {
+        let topics = (Symbol::new(e, "cancel_admin_transfer"), pool);
+        e.events()
+            .publish(topics, Self::transfer_details(admin_transfer));
+    }
+
+    /// Emitted when the pre-escrow status of a pool could not be restored as its admin transfer
+    /// was cancelled
+    ///
+    /// - topics - `["restore_status_failed", pool: Address]`
+    /// - data - `status: u32`
+    pub fn restore_status_failed(e: &Env, pool: Address, status: u32) {
+        let topics = (Symbol::new(e, "restore_status_failed"), pool);
+        e.events().publish(topics, status);
+    }
+
+    /// Emitted when the committed new admin of an admin transfer is revealed
+    ///
+    /// - topics - `["reveal_new_admin", pool: Address]`
+    /// - data - `new_admin: Address`
+    pub fn reveal_new_admin(e: &Env, pool: Address, new_admin: Address) {
+        let topics = (Symbol::new(e, "reveal_new_admin"), pool);
+        e.events().publish(topics, new_admin);
+    }
+
+    /// Emitted for each candidate new admin that was not chosen when an admin transfer completes
+    ///
+    /// - topics - `["reject_candidate", candidate: Address]`
+    /// - data - `[pool: Address, new_admin: Address]`
+    pub fn reject_candidate(e: &Env, candidate: Address, pool: Address, new_admin: Address) {
+        let topics = (Symbol::new(e, "reject_candidate"), candidate);
+        e.events().publish(topics, (pool, new_admin));
+    }
+
+    /// Emitted when an address is added to the approved new admins
+    ///
+    /// - topics - `["add_approved_admin", owner: Address]`
+    /// - data - `new_admin: Address`
+    pub fn add_approved_admin(e: &Env, owner: Address, new_admin: Address) {
+        let topics = (Symbol::new(e, "add_approved_admin"), owner);
+        e.events().publish(topics, new_admin);
+    }
+
+    /// Emitted when an address is removed from the approved new admins
+    ///
+    /// - topics - `["remove_approved_admin", owner: Address]`
+    /// - data - `new_admin: Address`
+    pub fn remove_approved_admin(e: &Env, owner: Address, new_admin: Address) {
+        let topics = (Symbol::new(e, "remove_approved_admin"), owner);
+        e.events().publish(topics, new_admin);
+    }
+
+    /// Emitted when a WASM hash is added to the approved WASM hashes
+    ///
+    /// - topics - `["add_approved_wasm", owner: Address]`
+    /// - data - `wasm_hash: BytesN<32>`
+    pub fn add_approved_wasm(e: &Env, owner: Address, wasm_hash: BytesN<32>) {
+        let topics = (Symbol::new(e, "add_approved_wasm"), owner);
+        e.events().publish(topics, wasm_hash);
+    }
+
+    /// Emitted when a WASM hash is removed from the approved WASM hashes
+    ///
+    /// - topics - `["remove_approved_wasm", owner: Address]`
+    /// - data - `wasm_hash: BytesN<32>`
+    pub fn remove_approved_wasm(e: &Env, owner: Address, wasm_hash: BytesN<32>) {
+        let topics = (Symbol::new(e, "remove_approved_wasm"), owner);
+        e.events().publish(topics, wasm_hash);
+    }
+
+    /// Emitted when a new owner of the contract is proposed
+    ///
+    /// - topics - `["propose_owner", owner: Address]`
+    /// - data - `proposal: OwnerProposal`
+    pub fn propose_owner(e: &Env, owner: Address, proposal: OwnerProposal) {
+        let topics = (Symbol::new(e, "propose_owner"), owner);
+        e.events().publish(topics, proposal);
+    }
+
+    /// Emitted when the proposed new owner accepts ownership of the contract
+    ///
+    /// - topics - `["accept_owner", new_owner: Address]`
+    /// - data - `prev_owner: Address`
+    pub fn accept_owner(e: &Env, new_owner: Address, prev_owner: Address) {
+        let topics = (Symbol::new(e, "accept_owner"), new_owner);
+        e.events().publish(topics, prev_owner);
+    }
+
+    /// Emitted when a proposed new owner of the contract is cancelled
+    ///
+    /// - topics - `["cancel_owner_proposal", owner: Address]`
+    /// - data - `new_owner: Address`
+    pub fn cancel_owner_proposal(e: &Env, owner: Address, new_owner: Address) {
+        let topics = (Symbol::new(e, "cancel_owner_proposal"), owner);
+        e.events().publish(topics, new_owner);
+    }
+
+    /// Emitted when the global configuration is changed
+    ///
+    /// - topics - `["set_config", owner: Address]`
+    /// - data - `config: Config`
+    pub fn set_config(e: &Env, owner: Address, config: Config) {
+        let topics = (Symbol::new(e, "set_config"), owner);
+        e.events().publish(topics, config);
+    }
+
+    /// Emitted when the guardian cancels an admin transfer
+    ///
+    /// - topics - `["guardian_cancel", guardian: Address]`
+    /// - data - `pool: Address`
+    pub fn guardian_cancel(e: &Env, guardian: Address, pool: Address) {
+        let topics = (Symbol::new(e, "guardian_cancel"), guardian);
+        e.events().publish(topics, pool);
+    }
+
+    /// Emitted when the global policy contract is set
+    ///
+    /// - topics - `["set_policy", owner: Address]`
+    /// - data - `policy: Option<Address>`
+    pub fn set_policy(e: &Env, owner: Address, policy: Option<Address>) {
+        let topics = (Symbol::new(e, "set_policy"), owner);
+        e.events().publish(topics, policy);
+    }
+
+    /// Emitted when the bond posted for admin transfers is set
+    ///
+    /// - topics - `["set_bond_config", owner: Address]`
+    /// - data - `config: Option<BondConfig>`
+    pub fn set_bond_config(e: &Env, owner: Address, config: Option<BondConfig>) {
+        let topics = (Symbol::new(e, "set_bond_config"), owner);
+        e.events().publish(topics, config);
+    }
+
+    /// Emitted when the pool health checks are set
+    ///
+    /// - topics - `["set_health_config", owner: Address]`
+    /// - data - `config: Option<HealthConfig>`
+    pub fn set_health_config(e: &Env, owner: Address, config: Option<HealthConfig>) {
+        let topics = (Symbol::new(e, "set_health_config"), owner);
+        e.events().publish(topics, config);
+    }
+
+    /// Emitted when a contract upgrade is queued
+    ///
+    /// - topics - `["queue_upgrade", owner: Address]`
+    /// - data - `upgrade: QueuedUpgrade`
+    pub fn queue_upgrade(e: &Env, owner: Address, upgrade: QueuedUpgrade) {
+        let topics = (Symbol::new(e, "queue_upgrade"), owner);
+        e.events().publish(topics, upgrade);
+    }
+
+    /// Emitted when a queued contract upgrade is cancelled
+    ///
+    /// - topics - `["cancel_upgrade", owner: Address]`
+    /// - data - `wasm_hash: BytesN<32>`
+    pub fn cancel_upgrade(e: &Env, owner: Address, wasm_hash: BytesN<32>) {
+        let topics = (Symbol::new(e, "cancel_upgrade"), owner);
+        e.events().publish(topics, wasm_hash);
+    }
+
+    /// Emitted when a queued contract upgrade is executed
+    ///
+    /// - topics - `["upgrade", owner: Address]`
+    /// - data - `wasm_hash: BytesN<32>`
+    pub fn upgrade(e: &Env, owner: Address, wasm_hash: BytesN<32>) {
+        let topics = (Symbol::new(e, "upgrade"), owner);
+        e.events().publish(topics, wasm_hash);
+    }
+
+    /// Emitted when the pending admin transfer of a pool is migrated to a new storage layout
+    ///
+    /// - topics - `["migrate_transfer", pool: Address]`
+    /// - data - `()`
+    pub fn migrate_admin_transfer(e: &Env, pool: Address) {
+        let topics = (Symbol::new(e, "migrate_transfer"), pool);
+        e.events().publish(topics, ());
+    }
+
+    /// The details of an admin transfer included in its lifecycle events
+    fn transfer_details(
+        admin_transfer: &TransferData,
+    ) -> (Address, Option<Address>, String, ProposalReference) {
+        let new_admin = match &admin_transfer.new_admin {
+            NewAdmin::Address(new_admin) => Some(new_admin.clone()),
+            NewAdmin::Committed(..) | NewAdmin::Candidates(_) => None,
+        };
+        (
+            admin_transfer.cur_admin.clone(),
+            new_admin,
+            admin_transfer.memo.clone(),
+            admin_transfer.proposal.clone(),
+        )
+    }
+}
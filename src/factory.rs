@@ -0,0 +1,11 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+/// Interface of an escrow factory that deploys admin transfer escrows
+#[contractclient(name = "EscrowFactoryClient")]
+pub trait EscrowFactory {
+    /// Check if an address is an escrow deployed by the factory
+    ///
+    /// ### Arguments
+    /// * `escrow` - The address to check
+    fn is_escrow(e: Env, escrow: Address) -> bool;
+}
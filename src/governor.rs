@@ -0,0 +1,72 @@
+use soroban_sdk::{contractclient, contracttype, Address, Env, String, Symbol, Val, Vec};
+
+/// The status of a governor proposal
+#[contracttype]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u32)]
+pub enum ProposalStatus {
+    Open = 0,
+    Successful = 1,
+    Defeated = 2,
+    Expired = 3,
+    Executed = 4,
+    Canceled = 5,
+}
+
+/// A governor proposal
+#[contracttype]
+#[derive(Clone)]
+pub struct Proposal {
+    pub id: u32,
+    pub config: ProposalConfig,
+    pub data: ProposalData,
+}
+
+/// The configuration of a governor proposal. The action is left as the vector its enum is
+/// encoded as, so any kind of action can be read, and is decoded as a `ProposalAction` if needed.
+#[contracttype]
+#[derive(Clone)]
+pub struct ProposalConfig {
+    pub title: String,
+    pub description: String,
+    pub action: Vec<Val>,
+}
+
+/// The state of a governor proposal
+#[contracttype]
+#[derive(Clone)]
+pub struct ProposalData {
+    pub creator: Address,
+    pub vote_start: u32,
+    pub vote_end: u32,
+    pub eta: u32,
+    pub status: ProposalStatus,
+    pub executable: bool,
+}
+
+/// The action of a governor proposal that invokes a contract
+#[contracttype]
+#[derive(Clone)]
+pub enum ProposalAction {
+    Calldata(Calldata),
+}
+
+/// A contract invocation made by a governor when a proposal executes
+#[contracttype]
+#[derive(Clone)]
+pub struct Calldata {
+    pub contract_id: Address,
+    pub function: Symbol,
+    pub args: Vec<Val>,
+    pub auths: Vec<Val>,
+}
+
+/// Interface of a governor contract used to read its proposals
+#[contractclient(name = "GovernorClient")]
+pub trait Governor {
+    /// Get a proposal, or None if the proposal does not exist
+    ///
+    /// ### Arguments
+    /// * `proposal_id` - The id of the proposal
+    fn get_proposal(e: Env, proposal_id: u32) -> Option<Proposal>;
+}
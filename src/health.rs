@@ -0,0 +1,66 @@
+use blend_contract_sdk::{backstop, pool};
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+/// The conditions a pool must meet for an admin transfer to complete
+#[contracttype]
+#[derive(Clone)]
+pub struct HealthConfig {
+    /// The backstop contract of the pool
+    pub backstop: Address,
+    /// The highest pool status allowed (e.g. 1 rejects pools that are on-ice or frozen)
+    pub max_status: u32,
+    /// The minimum amount of backstop tokens deposited for the pool
+    pub min_backstop_tokens: i128,
+    /// Whether any bad debt held by the backstop in the pool fails the check
+    pub no_bad_debt: bool,
+}
+
+/// A failed health check for a pool
+#[contracttype]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum HealthFailure {
+    /// The pool status is above the maximum status. Contains the pool status.
+    PoolStatus(u32),
+    /// The backstop holds bad debt in the pool. Contains the reserve index and the d_tokens owed.
+    BadDebt(u32, i128),
+    /// The backstop has too few tokens deposited for the pool. Contains the backstop tokens.
+    BackstopTokens(i128),
+}
+
+/// Check the health of a pool and return every failed check
+///
+/// ### Arguments
+/// * `pool` - The address of the pool
+/// * `config` - The conditions the pool must meet
+/// * `status` - The status the pool will have when the check applies. The pool does not expose
+///   its status, so it is tracked by the admin transfer.
+pub fn check_pool_health(
+    e: &Env,
+    pool: &Address,
+    config: &HealthConfig,
+    status: u32,
+) -> Vec<HealthFailure> {
+    let mut failures = Vec::new(e);
+    let pool_client = pool::Client::new(e, pool);
+
+    if status > config.max_status {
+        failures.push_back(HealthFailure::PoolStatus(status));
+    }
+
+    if config.no_bad_debt {
+        let backstop_positions = pool_client.get_positions(&config.backstop);
+        for (reserve_index, d_tokens) in backstop_positions.liabilities.iter() {
+            if d_tokens > 0 {
+                failures.push_back(HealthFailure::BadDebt(reserve_index, d_tokens));
+            }
+        }
+    }
+
+    let backstop_client = backstop::Client::new(e, &config.backstop);
+    let pool_backstop_data = backstop_client.pool_data(pool);
+    if pool_backstop_data.tokens < config.min_backstop_tokens {
+        failures.push_back(HealthFailure::BackstopTokens(pool_backstop_data.tokens));
+    }
+
+    failures
+}
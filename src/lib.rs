@@ -3,11 +3,40 @@
 #[cfg(test)]
 extern crate std;
 
+use soroban_sdk::contractmeta;
+
+mod actions;
+mod adapter;
+mod bond;
 mod contract;
 mod errors;
+mod events;
+mod factory;
+mod governor;
+mod health;
+mod policy;
+mod receiver;
+mod reserves;
 mod storage;
+mod version;
 
+pub use actions::*;
+pub use adapter::*;
+pub use bond::*;
 pub use contract::*;
+pub use factory::*;
+pub use governor::*;
+pub use health::*;
+pub use policy::*;
+pub use receiver::*;
+pub use version::*;
+
+contractmeta!(key = "name", val = "pool-admin-transfer");
+contractmeta!(key = "version", val = "1.0.0");
+contractmeta!(
+    key = "repo",
+    val = "https://github.com/blend-capital/pool-admin-transfer"
+);
 
 #[cfg(test)]
 mod test;
@@ -0,0 +1,29 @@
+use soroban_sdk::{contractclient, contracttype, Address, Env};
+
+/// The admin transfer action a policy contract is asked to approve
+#[contracttype]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TransferAction {
+    Set,
+    Transfer,
+    Cancel,
+}
+
+/// Interface for a policy contract that can veto admin transfer actions
+#[contractclient(name = "TransferPolicyClient")]
+pub trait TransferPolicy {
+    /// Check if an admin transfer action is allowed. The action is vetoed if this returns an error.
+    ///
+    /// ### Arguments
+    /// * `pool` - The address of the pool the admin transfer is for
+    /// * `cur_admin` - The current admin of the pool
+    /// * `new_admin` - The new admin of the pool
+    /// * `action` - The action being taken on the admin transfer
+    fn check_transfer(
+        e: Env,
+        pool: Address,
+        cur_admin: Address,
+        new_admin: Address,
+        action: TransferAction,
+    );
+}
@@ -0,0 +1,12 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+/// Interface a new admin contract can implement to be notified when it receives a pool
+#[contractclient(name = "AdminReceiverClient")]
+pub trait AdminReceiver {
+    /// Called on the new admin after it has been set as the admin of a pool
+    ///
+    /// ### Arguments
+    /// * `pool` - The address of the pool the new admin received
+    /// * `from` - The previous admin of the pool
+    fn on_pool_admin_received(e: Env, pool: Address, from: Address);
+}
@@ -0,0 +1,53 @@
+use blend_contract_sdk::pool::Client;
+use soroban_sdk::{panic_with_error, Address, Env, Error};
+
+use crate::{errors::ContractError, storage::QueuedReserveMode};
+
+/// The `PoolError::InitNotUnlocked` error code of a Blend pool
+const POOL_INIT_NOT_UNLOCKED: u32 = 1203;
+
+/// Handle any queued reserve updates on the pool based on the mode. The escrow must be the
+/// admin of the pool.
+///
+/// The pool does not expose its reserves or queued reserve updates, so only the assets listed
+/// by the mode are checked. A queued initialization of a new reserve is detected if its asset
+/// is listed.
+///
+/// ### Arguments
+/// * `pool` - The address of the pool
+/// * `mode` - How queued reserve updates are handled
+///
+/// ### Panics
+/// * `QueuedReserveUpdate` - If the mode is `Refuse` and a reserve update is queued
+pub fn handle_queued_reserves(e: &Env, pool: &Address, mode: &QueuedReserveMode) {
+    let pool_client = Client::new(e, pool);
+    match mode {
+        QueuedReserveMode::Ignore => {}
+        QueuedReserveMode::Refuse(assets) => {
+            for asset in assets.iter() {
+                if has_queued_reserve(&pool_client, &asset) {
+                    panic_with_error!(e, ContractError::QueuedReserveUpdate);
+                }
+            }
+        }
+        QueuedReserveMode::Cancel(assets) => {
+            for asset in assets.iter() {
+                pool_client.cancel_set_reserve(&asset);
+            }
+        }
+    }
+}
+
+/// Check if a reserve update is queued for an asset
+///
+/// The pool does not expose queued reserve updates, so this attempts to apply the update. The
+/// attempt succeeds if an unlocked update is queued and fails with `InitNotUnlocked` if a locked
+/// update is queued. Any other failure means no update is queued. Any applied update is
+/// reverted, as a queued update causes the caller to panic.
+fn has_queued_reserve(pool_client: &Client, asset: &Address) -> bool {
+    match pool_client.try_set_reserve(asset) {
+        Ok(_) => true,
+        Err(Ok(error)) => error == Error::from_contract_error(POOL_INIT_NOT_UNLOCKED),
+        Err(Err(_)) => false,
+    }
+}
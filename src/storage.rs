@@ -1,15 +1,254 @@
-use soroban_sdk::{contracttype, Address, Env};
+use soroban_sdk::{contracttype, Address, BytesN, Env, String, Symbol, TryFromVal, Val, Vec};
+
+use crate::{
+    actions::PoolAction,
+    adapter::TargetKind,
+    bond::{Bond, BondConfig, BondTotals},
+    health::HealthConfig,
+};
 
 #[contracttype]
 pub struct TransferData {
+    pub kind: TargetKind,
     pub cur_admin: Address,
-    pub new_admin: Address,
+    pub new_admin: NewAdmin,
+    pub receive_hook: ReceiveHook,
+    pub policy: PolicySource,
+    pub queued_reserves: QueuedReserveMode,
+    pub actions: Vec<PoolAction>,
+    pub escrow_status: EscrowStatus,
+    pub prev_status: u32,
+    pub unlock_ledger: u32,
+    pub expiration_ledger: u32,
+    pub memo: String,
+    pub proposal: ProposalReference,
+    pub bind_proposal: bool,
+    pub hashlock: Hashlock,
+}
+
+/// An external governance proposal an admin transfer is made for
+#[contracttype]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ProposalReference {
+    /// The admin transfer does not reference a proposal
+    None,
+    /// The proposal with the given id on the given governor contract
+    Governor(Address, u32),
+}
+
+/// A hashlock an admin transfer can only complete with the preimage of
+#[contracttype]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Hashlock {
+    /// The admin transfer is not hashlocked
+    None,
+    /// The SHA-256 hash of the preimage
+    Sha256(BytesN<32>),
+}
+
+/// The new admin of an admin transfer
+#[contracttype]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum NewAdmin {
+    /// The new admin
+    Address(Address),
+    /// A commitment to the new admin that has not been revealed. Contains the SHA-256 hash of
+    /// the new admin and salt, if the new admin can be unverified, and if an unverified new
+    /// admin can be an account.
+    Committed(BytesN<32>, bool, bool),
+    /// The candidate new admins, one of which becomes the new admin when it accepts the transfer
+    Candidates(Vec<Address>),
+}
+
+/// The global configuration of the contract
+#[contracttype]
+#[derive(Clone)]
+pub struct Config {
+    /// The number of ledgers an admin transfer is locked for after it is created, unless the
+    /// transfer sets its own timelock
+    pub default_timelock: u32,
+    /// The maximum number of ledgers an admin transfer can be pending for before it expires
+    pub max_expiry: u32,
+    /// Escrow factories whose escrows cannot be the new admin of an admin transfer
+    pub trusted_factories: Vec<Address>,
+    /// The address that can cancel any admin transfer in an emergency
+    pub guardian: Address,
+    /// The number of ledgers after an admin transfer completes before a new admin transfer can
+    /// be created for the same pool
+    pub cooldown: u32,
+}
+
+/// How the `on_pool_admin_received` hook on the new admin is handled when the transfer completes
+#[contracttype]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ReceiveHook {
+    /// The hook is not invoked
+    Disabled,
+    /// The hook is invoked and the transfer fails if the hook fails
+    Fatal,
+    /// The hook is invoked and any failure is ignored
+    Ignorable,
+}
+
+/// The policy contract consulted for an admin transfer
+#[contracttype]
+#[derive(Clone, PartialEq, Eq)]
+pub enum PolicySource {
+    /// Use the global policy contract, if one is set
+    Global,
+    /// Use the given policy contract in addition to the global policy contract. The global
+    /// policy contract is always consulted first, if one is set.
+    Contract(Address),
+}
+
+/// How reserve updates queued on the pool are handled when an admin transfer is created or
+/// completed. The pool does not expose its reserves, so each mode lists the reserve assets it
+/// checks. A reserve update queued for an asset that is not listed is left in place.
+#[contracttype]
+#[derive(Clone, PartialEq, Eq)]
+pub enum QueuedReserveMode {
+    /// Queued reserve updates are left in place
+    Ignore,
+    /// The action fails if a reserve update is queued for any of the assets
+    Refuse(Vec<Address>),
+    /// Any reserve updates queued for the assets are cancelled
+    Cancel(Vec<Address>),
+}
+
+/// The status of a pool while it is in escrow
+#[contracttype]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum EscrowStatus {
+    /// The pool status is not changed
+    Unchanged,
+    /// The pool status is set to the first status when escrow begins, and to the second status
+    /// when the transfer completes. The admin status matching the pre-escrow status is restored
+    /// if the transfer is cancelled.
+    Set(u32, u32),
+}
+
+/// The details for deploying a new admin contract
+#[contracttype]
+#[derive(Clone)]
+pub struct Deployment {
+    /// The WASM hash of the contract
+    pub wasm_hash: BytesN<32>,
+    /// The salt used to derive the contract address, together with the current admin and pool
+    pub salt: BytesN<32>,
+    /// The function invoked to initialize the contract
+    pub init_fn: Symbol,
+    /// The arguments for the initialize function
+    pub init_args: Vec<Val>,
+}
+
+/// Per-transfer options supplied when an admin transfer is created
+#[contracttype]
+#[derive(Clone)]
+pub struct TransferOptions {
+    /// The kind of contract the admin transfer is for. Options marked Blend pool only require
+    /// `TargetKind::BlendPool`.
+    pub kind: TargetKind,
+    /// Allow the new admin to be any contract instead of a contract deployed by
+    /// `deploy_and_set_admin_transfer` from a WASM hash that is still approved
+    pub allow_unverified: bool,
+    /// Allow an unverified new admin to be an account instead of a contract
+    pub allow_eoa: bool,
+    /// How the receive hook on the new admin is handled
+    pub receive_hook: ReceiveHook,
+    /// The policy contract consulted for this transfer
+    pub policy: PolicySource,
+    /// How queued reserve updates on the pool are handled (Blend pool only)
+    pub queued_reserves: QueuedReserveMode,
+    /// Pool admin actions executed in order immediately before the new admin is set (Blend pool only)
+    pub actions: Vec<PoolAction>,
+    /// The status of the pool while it is in escrow (Blend pool only)
+    pub escrow_status: EscrowStatus,
+    /// The status of the pool when the admin transfer is created (Blend pool only). The pool
+    /// does not expose its status, so it is supplied by the current admin. It is the status
+    /// checked by the health checks unless the escrow status sets one, and its admin status is
+    /// restored if the escrow status is set and the transfer is cancelled.
+    pub pool_status: u32,
+    /// The number of ledgers the transfer is locked for after it is created, or 0 to use the
+    /// default timelock
+    pub timelock: u32,
+    /// The number of ledgers the transfer can be pending for before it expires, or 0 to use the
+    /// maximum expiry
+    pub expiry: u32,
+    /// A short description of why the admin is being transferred, or empty for no memo
+    pub memo: String,
+    /// The governance proposal the admin transfer is made for
+    pub proposal: ProposalReference,
+    /// Require the admin transfer to be completed by the proposal. The proposal must be on the new
+    /// admin, open or successful when the transfer is created, and invoke `transfer_admin` or
+    /// `transfer_admin_with_preimage` on this contract for the pool.
+    /// The new admin does not need to authorize the transfer.
+    pub bind_proposal: bool,
+    /// The SHA-256 hashlock of the admin transfer. If set, the admin transfer can only complete
+    /// through `transfer_admin_with_preimage`.
+    pub hashlock: Hashlock,
+}
+
+/// A proposed new owner of the contract waiting to accept ownership
+#[contracttype]
+#[derive(Clone)]
+pub struct OwnerProposal {
+    /// The proposed new owner
+    pub new_owner: Address,
+    /// The ledger sequence the proposal expires at
+    pub expiration_ledger: u32,
+}
+
+/// A contract upgrade waiting for its timelock to expire
+#[contracttype]
+#[derive(Clone)]
+pub struct QueuedUpgrade {
+    /// The WASM hash the contract is upgraded to
+    pub wasm_hash: BytesN<32>,
+    /// The ledger sequence the upgrade can be executed at
+    pub unlock_ledger: u32,
+}
+
+//********** Storage Keys **********//
+
+#[contracttype]
+pub enum DataKey {
+    // Instance
+    Owner,
+    OwnerProposal,
+    Config,
+    Pool,
+    ApprovedAdmins,
+    ApprovedWasms,
+    Policy,
+    HealthConfig,
+    BondConfig,
+    QueuedUpgrade,
+    StorageVersion,
+    // Persistent
+    AdminTransfer(Address),
+    Cooldown(Address),
+    Bond(Address),
+    BondTotals(Address),
+    Deployment(Address),
 }
 
 //********** Storage Utils **********//
 
 pub const ONE_DAY_LEDGERS: u32 = 17280; // assumes 5 seconds per ledger on average
 
+/// The maximum length of an admin transfer memo in bytes
+pub const MAX_MEMO_LENGTH: u32 = 64;
+
+/// The maximum number of candidate new admins of an admin transfer
+pub const MAX_CANDIDATES: u32 = 5;
+
+/// The storage layout version used by this contract. Must be incremented whenever the layout of
+/// stored data changes, alongside a migration.
+pub const STORAGE_VERSION: u32 = 2;
+
+/// The number of ledgers a queued upgrade must wait before it can be executed
+pub const UPGRADE_DELAY_LEDGERS: u32 = 7 * ONE_DAY_LEDGERS;
+
 const LEDGER_BUMP_SHARED: u32 = 31 * ONE_DAY_LEDGERS;
 const LEDGER_THRESHOLD_SHARED: u32 = LEDGER_BUMP_SHARED - ONE_DAY_LEDGERS;
 
@@ -23,6 +262,209 @@ pub fn extend_instance(e: &Env) {
         .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
 }
 
+/********** Instance **********/
+
+/// Check if the contract has been initialized
+pub fn has_owner(e: &Env) -> bool {
+    e.storage().instance().has(&DataKey::Owner)
+}
+
+/// Get the owner of the contract
+pub fn get_owner(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&DataKey::Owner)
+}
+
+/// Set the owner of the contract
+///
+/// ### Arguments
+/// * `owner` - The owner of the contract
+pub fn set_owner(e: &Env, owner: &Address) {
+    e.storage()
+        .instance()
+        .set::<DataKey, Address>(&DataKey::Owner, owner);
+}
+
+/// Get the proposed new owner of the contract
+pub fn get_owner_proposal(e: &Env) -> Option<OwnerProposal> {
+    e.storage().instance().get(&DataKey::OwnerProposal)
+}
+
+/// Set the proposed new owner of the contract
+///
+/// ### Arguments
+/// * `proposal` - The owner proposal, or None to remove it
+pub fn set_owner_proposal(e: &Env, proposal: &Option<OwnerProposal>) {
+    match proposal {
+        Some(proposal) => e
+            .storage()
+            .instance()
+            .set::<DataKey, OwnerProposal>(&DataKey::OwnerProposal, proposal),
+        None => e.storage().instance().remove(&DataKey::OwnerProposal),
+    }
+}
+
+/// Get the global configuration of the contract
+pub fn get_config(e: &Env) -> Option<Config> {
+    e.storage().instance().get(&DataKey::Config)
+}
+
+/// Set the global configuration of the contract
+///
+/// ### Arguments
+/// * `config` - The global configuration
+pub fn set_config(e: &Env, config: &Config) {
+    e.storage()
+        .instance()
+        .set::<DataKey, Config>(&DataKey::Config, config);
+}
+
+/// Get the only pool the contract is an escrow for
+pub fn get_pool(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&DataKey::Pool)
+}
+
+/// Set the only pool the contract is an escrow for
+///
+/// ### Arguments
+/// * `pool` - The address of the pool
+pub fn set_pool(e: &Env, pool: &Address) {
+    e.storage()
+        .instance()
+        .set::<DataKey, Address>(&DataKey::Pool, pool);
+}
+
+/// Get the list of approved new admins
+pub fn get_approved_admins(e: &Env) -> Vec<Address> {
+    e.storage()
+        .instance()
+        .get(&DataKey::ApprovedAdmins)
+        .unwrap_or(Vec::new(e))
+}
+
+/// Set the list of approved new admins
+///
+/// ### Arguments
+/// * `approved_admins` - The list of approved new admins
+pub fn set_approved_admins(e: &Env, approved_admins: &Vec<Address>) {
+    e.storage()
+        .instance()
+        .set::<DataKey, Vec<Address>>(&DataKey::ApprovedAdmins, approved_admins);
+}
+
+/// Get the list of approved WASM hashes for new admin contracts
+pub fn get_approved_wasms(e: &Env) -> Vec<BytesN<32>> {
+    e.storage()
+        .instance()
+        .get(&DataKey::ApprovedWasms)
+        .unwrap_or(Vec::new(e))
+}
+
+/// Set the list of approved WASM hashes for new admin contracts
+///
+/// ### Arguments
+/// * `approved_wasms` - The list of approved WASM hashes
+pub fn set_approved_wasms(e: &Env, approved_wasms: &Vec<BytesN<32>>) {
+    e.storage()
+        .instance()
+        .set::<DataKey, Vec<BytesN<32>>>(&DataKey::ApprovedWasms, approved_wasms);
+}
+
+/// Get the global policy contract
+pub fn get_policy(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&DataKey::Policy)
+}
+
+/// Set the global policy contract
+///
+/// ### Arguments
+/// * `policy` - The global policy contract, or None to remove it
+pub fn set_policy(e: &Env, policy: &Option<Address>) {
+    match policy {
+        Some(policy) => e
+            .storage()
+            .instance()
+            .set::<DataKey, Address>(&DataKey::Policy, policy),
+        None => e.storage().instance().remove(&DataKey::Policy),
+    }
+}
+
+/// Get the pool health checks required to complete an admin transfer
+pub fn get_health_config(e: &Env) -> Option<HealthConfig> {
+    e.storage().instance().get(&DataKey::HealthConfig)
+}
+
+/// Set the pool health checks required to complete an admin transfer
+///
+/// ### Arguments
+/// * `config` - The pool health checks, or None to disable them
+pub fn set_health_config(e: &Env, config: &Option<HealthConfig>) {
+    match config {
+        Some(config) => e
+            .storage()
+            .instance()
+            .set::<DataKey, HealthConfig>(&DataKey::HealthConfig, config),
+        None => e.storage().instance().remove(&DataKey::HealthConfig),
+    }
+}
+
+/// Get the bond the current admin posts when an admin transfer is created
+pub fn get_bond_config(e: &Env) -> Option<BondConfig> {
+    e.storage().instance().get(&DataKey::BondConfig)
+}
+
+/// Set the bond the current admin posts when an admin transfer is created
+///
+/// ### Arguments
+/// * `config` - The bond, or None to disable bonds
+pub fn set_bond_config(e: &Env, config: &Option<BondConfig>) {
+    match config {
+        Some(config) => e
+            .storage()
+            .instance()
+            .set::<DataKey, BondConfig>(&DataKey::BondConfig, config),
+        None => e.storage().instance().remove(&DataKey::BondConfig),
+    }
+}
+
+/// Get the contract upgrade waiting for its timelock to expire
+pub fn get_queued_upgrade(e: &Env) -> Option<QueuedUpgrade> {
+    e.storage().instance().get(&DataKey::QueuedUpgrade)
+}
+
+/// Set the contract upgrade waiting for its timelock to expire
+///
+/// ### Arguments
+/// * `upgrade` - The queued upgrade, or None to remove it
+pub fn set_queued_upgrade(e: &Env, upgrade: &Option<QueuedUpgrade>) {
+    match upgrade {
+        Some(upgrade) => e
+            .storage()
+            .instance()
+            .set::<DataKey, QueuedUpgrade>(&DataKey::QueuedUpgrade, upgrade),
+        None => e.storage().instance().remove(&DataKey::QueuedUpgrade),
+    }
+}
+
+/// Get the storage layout version of the stored data
+///
+/// Defaults to 1, the layout used before the version was recorded
+pub fn get_storage_version(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&DataKey::StorageVersion)
+        .unwrap_or(1)
+}
+
+/// Set the storage layout version of the stored data
+///
+/// ### Arguments
+/// * `version` - The storage layout version
+pub fn set_storage_version(e: &Env, version: u32) {
+    e.storage()
+        .instance()
+        .set::<DataKey, u32>(&DataKey::StorageVersion, &version);
+}
+
 /********** Persistent **********/
 
 /// Check if an admin transfer exists
@@ -30,7 +472,9 @@ pub fn extend_instance(e: &Env) {
 /// ### Arguments
 /// * `pool` - The address of the pool the admin transfer is for
 pub fn has_admin_transfer(e: &Env, pool: &Address) -> bool {
-    e.storage().persistent().has(&pool)
+    e.storage()
+        .persistent()
+        .has(&DataKey::AdminTransfer(pool.clone()))
 }
 
 /// Set the admin transfer details
@@ -39,12 +483,13 @@ pub fn has_admin_transfer(e: &Env, pool: &Address) -> bool {
 /// * `pool` - The address of the pool the admin transfer is for
 /// * `admin_transfer` - The admin transfer details
 pub fn set_admin_transfer(e: &Env, pool: &Address, admin_transfer: &TransferData) {
+    let key = DataKey::AdminTransfer(pool.clone());
     e.storage()
         .persistent()
-        .set::<Address, TransferData>(&pool, &admin_transfer);
+        .set::<DataKey, TransferData>(&key, admin_transfer);
     e.storage()
         .persistent()
-        .extend_ttl(&pool, LEDGER_THRESHOLD_TRANSFER, LEDGER_BUMP_TRANSFER);
+        .extend_ttl(&key, LEDGER_THRESHOLD_TRANSFER, LEDGER_BUMP_TRANSFER);
 }
 
 /// Get the new admin for an admin transfer
@@ -52,7 +497,9 @@ pub fn set_admin_transfer(e: &Env, pool: &Address, admin_transfer: &TransferData
 /// ### Arguments
 /// * `pool` - The address of the pool the admin transfer is for
 pub fn get_admin_transfer(e: &Env, pool: &Address) -> Option<TransferData> {
-    e.storage().persistent().get(&pool)
+    e.storage()
+        .persistent()
+        .get(&DataKey::AdminTransfer(pool.clone()))
 }
 
 /// Get the new admin for an admin transfer
@@ -60,5 +507,177 @@ pub fn get_admin_transfer(e: &Env, pool: &Address) -> Option<TransferData> {
 /// ### Arguments
 /// * `pool` - The address of the pool the admin transfer is for
 pub fn del_admin_transfer(e: &Env, pool: &Address) {
-    e.storage().persistent().remove(&pool)
+    e.storage()
+        .persistent()
+        .remove(&DataKey::AdminTransfer(pool.clone()))
+}
+
+/// Get the ledger sequence the cooldown of a pool ends at, or 0 if the pool has no cooldown
+///
+/// ### Arguments
+/// * `pool` - The address of the pool
+pub fn get_cooldown(e: &Env, pool: &Address) -> u32 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Cooldown(pool.clone()))
+        .unwrap_or(0)
+}
+
+/// Set the ledger sequence the cooldown of a pool ends at
+///
+/// ### Arguments
+/// * `pool` - The address of the pool
+/// * `end_ledger` - The ledger sequence the cooldown ends at
+pub fn set_cooldown(e: &Env, pool: &Address, end_ledger: u32) {
+    let key = DataKey::Cooldown(pool.clone());
+    e.storage()
+        .persistent()
+        .set::<DataKey, u32>(&key, &end_ledger);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_TRANSFER, LEDGER_BUMP_TRANSFER);
+}
+
+/// Get the bond held for an admin transfer
+///
+/// ### Arguments
+/// * `pool` - The address of the pool the admin transfer is for
+pub fn get_bond(e: &Env, pool: &Address) -> Option<Bond> {
+    e.storage().persistent().get(&DataKey::Bond(pool.clone()))
+}
+
+/// Set the bond held for an admin transfer
+///
+/// ### Arguments
+/// * `pool` - The address of the pool the admin transfer is for
+/// * `bond` - The bond held
+pub fn set_bond(e: &Env, pool: &Address, bond: &Bond) {
+    let key = DataKey::Bond(pool.clone());
+    e.storage().persistent().set::<DataKey, Bond>(&key, bond);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_TRANSFER, LEDGER_BUMP_TRANSFER);
+}
+
+/// Delete the bond held for an admin transfer
+///
+/// ### Arguments
+/// * `pool` - The address of the pool the admin transfer is for
+pub fn del_bond(e: &Env, pool: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::Bond(pool.clone()))
+}
+
+/// Get the bond accounting for a token
+///
+/// ### Arguments
+/// * `token` - The address of the bond token
+pub fn get_bond_totals(e: &Env, token: &Address) -> BondTotals {
+    e.storage()
+        .persistent()
+        .get(&DataKey::BondTotals(token.clone()))
+        .unwrap_or_default()
+}
+
+/// Set the bond accounting for a token
+///
+/// ### Arguments
+/// * `token` - The address of the bond token
+/// * `totals` - The bond accounting
+pub fn set_bond_totals(e: &Env, token: &Address, totals: &BondTotals) {
+    let key = DataKey::BondTotals(token.clone());
+    e.storage()
+        .persistent()
+        .set::<DataKey, BondTotals>(&key, totals);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_TRANSFER, LEDGER_BUMP_TRANSFER);
+}
+
+/// Get the WASM hash a contract was deployed from by `deploy_and_set_admin_transfer`
+///
+/// ### Arguments
+/// * `address` - The address of the contract
+pub fn get_deployment(e: &Env, address: &Address) -> Option<BytesN<32>> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Deployment(address.clone()))
+}
+
+/// Set the WASM hash a contract was deployed from by `deploy_and_set_admin_transfer`
+///
+/// ### Arguments
+/// * `address` - The address of the contract
+/// * `wasm_hash` - The WASM hash the contract was deployed from
+pub fn set_deployment(e: &Env, address: &Address, wasm_hash: &BytesN<32>) {
+    let key = DataKey::Deployment(address.clone());
+    e.storage()
+        .persistent()
+        .set::<DataKey, BytesN<32>>(&key, wasm_hash);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_TRANSFER, LEDGER_BUMP_TRANSFER);
+}
+
+/********** Migration **********/
+
+// Storage layout versions:
+// 1 - admin transfers of Blend pools under the pool address, without any instance entries
+// 2 - entries under `DataKey`
+
+/// The admin transfer details stored under storage version 1
+#[contracttype(export = false)]
+pub struct LegacyTransferData {
+    pub cur_admin: Address,
+    pub new_admin: Address,
+}
+
+/// Check if an admin transfer is stored under the storage version 1 pool address key
+///
+/// ### Arguments
+/// * `pool` - The address of the pool the admin transfer is for
+pub fn has_legacy_admin_transfer(e: &Env, pool: &Address) -> bool {
+    e.storage().persistent().has(pool)
+}
+
+/// Migrate an admin transfer stored under storage version 1 from the pool address key to its
+/// `DataKey`. Storage version 1 only supported Blend pools, so every option added since is set
+/// to its default. Returns false if the pool has no admin transfer to migrate, or if the stored
+/// admin transfer cannot be decoded, in which case it is left in place.
+///
+/// ### Arguments
+/// * `pool` - The address of the pool the admin transfer is for
+pub fn migrate_admin_transfer(e: &Env, pool: &Address) -> bool {
+    let legacy = match e
+        .storage()
+        .persistent()
+        .get::<Address, Val>(pool)
+        .and_then(|data| LegacyTransferData::try_from_val(e, &data).ok())
+    {
+        Some(legacy) => legacy,
+        None => return false,
+    };
+
+    let admin_transfer = TransferData {
+        kind: TargetKind::BlendPool,
+        cur_admin: legacy.cur_admin,
+        new_admin: NewAdmin::Address(legacy.new_admin),
+        receive_hook: ReceiveHook::Disabled,
+        policy: PolicySource::Global,
+        queued_reserves: QueuedReserveMode::Ignore,
+        actions: Vec::new(e),
+        escrow_status: EscrowStatus::Unchanged,
+        prev_status: 0,
+        // admin transfers stored under storage version 1 are unlocked and never expire
+        unlock_ledger: 0,
+        expiration_ledger: u32::MAX,
+        memo: String::from_str(e, ""),
+        proposal: ProposalReference::None,
+        bind_proposal: false,
+        hashlock: Hashlock::None,
+    };
+    set_admin_transfer(e, pool, &admin_transfer);
+    e.storage().persistent().remove(pool);
+    true
 }
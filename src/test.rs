@@ -1,14 +1,36 @@
 #![cfg(test)]
 
-use crate::{errors::ContractError, testutils::EnvTestUtils, AdminTransferClient};
-use blend_contract_sdk::pool::Client as PoolClient;
+use crate::{
+    actions::PoolAction,
+    adapter::TargetKind,
+    bond::BondConfig,
+    errors::ContractError,
+    governor::ProposalStatus,
+    health::{HealthConfig, HealthFailure},
+    policy::TransferAction,
+    storage::{
+        self, Config, Deployment, EscrowStatus, Hashlock, LegacyTransferData, NewAdmin,
+        PolicySource, ProposalReference, QueuedReserveMode, ReceiveHook, TransferOptions,
+        ONE_DAY_LEDGERS,
+    },
+    testutils::{
+        default_config, default_transfer_options, get_pool_config, EnvTestUtils, MockAdminReceiver,
+        MockAdminReceiverClient, MockGovernor, MockGovernorClient, MockPolicy, MockPolicyClient,
+        MockTwoStepAdmin, MockTwoStepAdminClient,
+    },
+    AdminTransferClient,
+};
+use blend_contract_sdk::pool::{Client as PoolClient, ReserveConfig};
 use blend_contract_sdk::testutils::BlendFixture;
 use soroban_sdk::{
     testutils::{
-        Address as _, AuthorizedFunction, AuthorizedInvocation, BytesN as _, MockAuth,
+        Address as _, AuthorizedFunction, AuthorizedInvocation, BytesN as _, Events, MockAuth,
         MockAuthInvoke,
     },
-    vec, Address, BytesN, Env, Error, IntoVal, String, Symbol,
+    token::{StellarAssetClient, TokenClient},
+    vec,
+    xdr::{Limits, ScMetaEntry, ScMetaV0, ToXdr, WriteXdr},
+    Address, Bytes, BytesN, Env, Error, IntoVal, String, Symbol,
 };
 
 mod admin_transfer_wasm {
@@ -30,8 +52,15 @@ fn test_admin_transfer() {
     let usdc = env.register_stellar_asset_contract(admin.clone());
 
     let new_admin = Address::generate(&env);
+    let options = default_transfer_options(&env);
     let sauron = Address::generate(&env);
 
+    let owner = Address::generate(&env);
+    admin_transfer_client.initialize(&owner, &default_config(&env), &None);
+    admin_transfer_client
+        .mock_all_auths()
+        .add_approved_admin(&new_admin);
+
     let blend_fixture = BlendFixture::deploy(&env, &admin, &blnd, &usdc);
     let pool = blend_fixture.pool_factory.mock_all_auths().deploy(
         &admin,
@@ -62,6 +91,7 @@ fn test_admin_transfer() {
                     pool.clone().into_val(&env),
                     sauron.clone().into_val(&env),
                     new_admin.clone().into_val(&env),
+                    options.clone().into_val(&env),
                 ],
                 sub_invokes: &[MockAuthInvoke {
                     contract: &pool,
@@ -71,7 +101,7 @@ fn test_admin_transfer() {
                 }],
             },
         }])
-        .try_set_admin_transfer(&pool, &sauron, &new_admin);
+        .try_set_admin_transfer(&pool, &sauron, &new_admin, &options);
     assert!(result.is_err());
 
     // create admin transfer
@@ -86,6 +116,7 @@ fn test_admin_transfer() {
                     pool.clone().into_val(&env),
                     admin.clone().into_val(&env),
                     new_admin.clone().into_val(&env),
+                    options.clone().into_val(&env),
                 ],
                 sub_invokes: &[MockAuthInvoke {
                     contract: &pool,
@@ -95,7 +126,7 @@ fn test_admin_transfer() {
                 }],
             },
         }])
-        .set_admin_transfer(&pool, &admin, &new_admin);
+        .set_admin_transfer(&pool, &admin, &new_admin, &options);
 
     // -> validate auths
     assert_eq!(
@@ -111,6 +142,7 @@ fn test_admin_transfer() {
                         pool.clone().into_val(&env),
                         admin.clone().into_val(&env),
                         new_admin.clone().into_val(&env),
+                        options.clone().into_val(&env),
                     ]
                 )),
                 sub_invocations: std::vec![AuthorizedInvocation {
@@ -127,7 +159,7 @@ fn test_admin_transfer() {
 
     // -> validate chain state
     let result = admin_transfer_client.get_admin_transfer(&pool).unwrap();
-    assert_eq!(result.new_admin, new_admin);
+    assert_eq!(result.new_admin, NewAdmin::Address(new_admin.clone()));
     assert_eq!(result.cur_admin, admin);
 
     // -> validate admin is no longer the admin
@@ -148,7 +180,7 @@ fn test_admin_transfer() {
     // validate another admin transfer cannot be created
     let result = admin_transfer_client
         .mock_all_auths()
-        .try_set_admin_transfer(&pool, &admin, &sauron);
+        .try_set_admin_transfer(&pool, &admin, &sauron, &options);
     assert_eq!(
         result.err(),
         Some(Ok(Error::from_contract_error(
@@ -258,6 +290,13 @@ fn test_admin_transfer_cancel() {
     let usdc = env.register_stellar_asset_contract(admin.clone());
 
     let new_admin = Address::generate(&env);
+    let options = default_transfer_options(&env);
+
+    let owner = Address::generate(&env);
+    admin_transfer_client.initialize(&owner, &default_config(&env), &None);
+    admin_transfer_client
+        .mock_all_auths()
+        .add_approved_admin(&new_admin);
 
     let blend_fixture = BlendFixture::deploy(&env, &admin, &blnd, &usdc);
     let pool = blend_fixture.pool_factory.mock_all_auths().deploy(
@@ -281,6 +320,7 @@ fn test_admin_transfer_cancel() {
                     pool.clone().into_val(&env),
                     admin.clone().into_val(&env),
                     new_admin.clone().into_val(&env),
+                    options.clone().into_val(&env),
                 ],
                 sub_invokes: &[MockAuthInvoke {
                     contract: &pool,
@@ -290,11 +330,11 @@ fn test_admin_transfer_cancel() {
                 }],
             },
         }])
-        .set_admin_transfer(&pool, &admin, &new_admin);
+        .set_admin_transfer(&pool, &admin, &new_admin, &options);
 
     // -> validate chain state
     let result = admin_transfer_client.get_admin_transfer(&pool).unwrap();
-    assert_eq!(result.new_admin, new_admin);
+    assert_eq!(result.new_admin, NewAdmin::Address(new_admin.clone()));
     assert_eq!(result.cur_admin, admin);
 
     // -> validate admin is no longer the admin
@@ -382,6 +422,7 @@ fn test_admin_transfer_cancel() {
                     pool.clone().into_val(&env),
                     admin.clone().into_val(&env),
                     new_admin.clone().into_val(&env),
+                    options.clone().into_val(&env),
                 ],
                 sub_invokes: &[MockAuthInvoke {
                     contract: &pool,
@@ -391,10 +432,2481 @@ fn test_admin_transfer_cancel() {
                 }],
             },
         }])
-        .set_admin_transfer(&pool, &admin, &new_admin);
+        .set_admin_transfer(&pool, &admin, &new_admin, &options);
 
     // -> validate chain state
     let result = admin_transfer_client.get_admin_transfer(&pool).unwrap();
-    assert_eq!(result.new_admin, new_admin);
+    assert_eq!(result.new_admin, NewAdmin::Address(new_admin.clone()));
     assert_eq!(result.cur_admin, admin);
 }
+
+#[test]
+fn test_set_admin_transfer_invalid_new_admin() {
+    let env = Env::default();
+    env.set_default_info();
+    env.mock_all_auths();
+
+    let admin_transfer_id = env.register_contract_wasm(None, admin_transfer_wasm::WASM);
+    let admin_transfer_client = AdminTransferClient::new(&env, &admin_transfer_id);
+
+    let admin = Address::generate(&env);
+    let blnd = env.register_stellar_asset_contract(admin.clone());
+    let usdc = env.register_stellar_asset_contract(admin.clone());
+
+    let options = default_transfer_options(&env);
+
+    let owner = Address::generate(&env);
+    admin_transfer_client.initialize(&owner, &default_config(&env), &None);
+
+    let blend_fixture = BlendFixture::deploy(&env, &admin, &blnd, &usdc);
+    let pool = blend_fixture.pool_factory.deploy(
+        &admin,
+        &String::from_str(&env, "test"),
+        &BytesN::<32>::random(&env),
+        &Address::generate(&env),
+        &0,
+        &2,
+    );
+
+    // validate the new admin cannot be the current admin
+    let result = admin_transfer_client.try_set_admin_transfer(&pool, &admin, &admin, &options);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::NewAdminIsCurrentAdmin as u32
+        )))
+    );
+
+    // validate the new admin cannot be the admin transfer contract
+    let result =
+        admin_transfer_client.try_set_admin_transfer(&pool, &admin, &admin_transfer_id, &options);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::NewAdminIsEscrow as u32
+        )))
+    );
+
+    // validate the new admin cannot be the pool
+    let result = admin_transfer_client.try_set_admin_transfer(&pool, &admin, &pool, &options);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::NewAdminIsPool as u32
+        )))
+    );
+
+    // validate the new admin must be verified unless unverified new admins are allowed
+    let eoa = Address::from_string(&String::from_str(
+        &env,
+        "GAAQEAYEAUDAOCAJBIFQYDIOB4IBCEQTCQKRMFYYDENBWHA5DYPSABOV",
+    ));
+    let verified_options = TransferOptions {
+        allow_unverified: false,
+        ..default_transfer_options(&env)
+    };
+    let result =
+        admin_transfer_client.try_set_admin_transfer(&pool, &admin, &eoa, &verified_options);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::NewAdminNotVerified as u32
+        )))
+    );
+    let result = admin_transfer_client.try_set_admin_transfer(
+        &pool,
+        &admin,
+        &Address::generate(&env),
+        &verified_options,
+    );
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::NewAdminNotVerified as u32
+        )))
+    );
+
+    // validate an unverified new admin must be a contract unless accounts are allowed
+    let result = admin_transfer_client.try_set_admin_transfer(&pool, &admin, &eoa, &options);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::NewAdminNotContract as u32
+        )))
+    );
+
+    // validate the new admin must be an approved new admin
+    let eoa_options = TransferOptions {
+        allow_eoa: true,
+        ..options.clone()
+    };
+    let result = admin_transfer_client.try_set_admin_transfer(&pool, &admin, &eoa, &eoa_options);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::NewAdminNotApproved as u32
+        )))
+    );
+
+    // -> validate no admin transfer was created
+    let result = admin_transfer_client.get_admin_transfer(&pool);
+    assert!(result.is_none());
+
+    admin_transfer_client.add_approved_admin(&eoa);
+    admin_transfer_client.set_admin_transfer(&pool, &admin, &eoa, &eoa_options);
+    let result = admin_transfer_client.get_admin_transfer(&pool).unwrap();
+    assert_eq!(result.new_admin, NewAdmin::Address(eoa.clone()));
+}
+
+#[test]
+fn test_approved_admins() {
+    let env = Env::default();
+    env.set_default_info();
+
+    let admin_transfer_id = env.register_contract_wasm(None, admin_transfer_wasm::WASM);
+    let admin_transfer_client = AdminTransferClient::new(&env, &admin_transfer_id);
+
+    let owner = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+    let sauron = Address::generate(&env);
+
+    // validate the approved admins cannot be modified before initialization
+    let result = admin_transfer_client
+        .mock_all_auths()
+        .try_add_approved_admin(&new_admin);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::NotInitialized as u32
+        )))
+    );
+
+    admin_transfer_client.initialize(&owner, &default_config(&env), &None);
+    assert_eq!(admin_transfer_client.get_owner(), Some(owner.clone()));
+
+    // validate the contract cannot be initialized twice
+    let result = admin_transfer_client.try_initialize(&sauron, &default_config(&env), &None);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::AlreadyInitializedError as u32
+        )))
+    );
+
+    // validate only the owner can add an approved admin
+    let result = admin_transfer_client
+        .mock_auths(&[MockAuth {
+            address: &sauron,
+            invoke: &MockAuthInvoke {
+                contract: &admin_transfer_id,
+                fn_name: &"add_approved_admin",
+                args: vec![&env, sauron.clone().into_val(&env)],
+                sub_invokes: &[],
+            },
+        }])
+        .try_add_approved_admin(&sauron);
+    assert!(result.is_err());
+
+    // add an approved admin
+    admin_transfer_client
+        .mock_auths(&[MockAuth {
+            address: &owner,
+            invoke: &MockAuthInvoke {
+                contract: &admin_transfer_id,
+                fn_name: &"add_approved_admin",
+                args: vec![&env, new_admin.clone().into_val(&env)],
+                sub_invokes: &[],
+            },
+        }])
+        .add_approved_admin(&new_admin);
+
+    // -> validate event
+    let events = env.events().all();
+    let event = vec![&env, events.get_unchecked(events.len() - 1)];
+    assert_eq!(
+        event,
+        vec![
+            &env,
+            (
+                admin_transfer_id.clone(),
+                (Symbol::new(&env, "add_approved_admin"), owner.clone()).into_val(&env),
+                new_admin.clone().into_val(&env)
+            )
+        ]
+    );
+
+    // -> validate chain state
+    assert_eq!(
+        admin_transfer_client.get_approved_admins(),
+        vec![&env, new_admin.clone()]
+    );
+
+    // validate adding an approved admin twice does not duplicate it or emit an event
+    let event_count = env.events().all().len();
+    admin_transfer_client
+        .mock_all_auths()
+        .add_approved_admin(&new_admin);
+    assert_eq!(env.events().all().len(), event_count);
+    assert_eq!(
+        admin_transfer_client.get_approved_admins(),
+        vec![&env, new_admin.clone()]
+    );
+
+    // remove an approved admin
+    admin_transfer_client
+        .mock_auths(&[MockAuth {
+            address: &owner,
+            invoke: &MockAuthInvoke {
+                contract: &admin_transfer_id,
+                fn_name: &"remove_approved_admin",
+                args: vec![&env, new_admin.clone().into_val(&env)],
+                sub_invokes: &[],
+            },
+        }])
+        .remove_approved_admin(&new_admin);
+
+    // -> validate event
+    let events = env.events().all();
+    let event = vec![&env, events.get_unchecked(events.len() - 1)];
+    assert_eq!(
+        event,
+        vec![
+            &env,
+            (
+                admin_transfer_id.clone(),
+                (Symbol::new(&env, "remove_approved_admin"), owner.clone()).into_val(&env),
+                new_admin.clone().into_val(&env)
+            )
+        ]
+    );
+
+    // -> validate chain state
+    assert_eq!(admin_transfer_client.get_approved_admins().len(), 0);
+
+    // validate removing an address that is not approved does not emit an event
+    let event_count = env.events().all().len();
+    admin_transfer_client
+        .mock_all_auths()
+        .remove_approved_admin(&new_admin);
+    assert_eq!(env.events().all().len(), event_count);
+}
+
+#[test]
+fn test_admin_transfer_receive_hook() {
+    let env = Env::default();
+    env.set_default_info();
+    env.mock_all_auths();
+
+    let admin_transfer_id = env.register_contract_wasm(None, admin_transfer_wasm::WASM);
+    let admin_transfer_client = AdminTransferClient::new(&env, &admin_transfer_id);
+
+    let admin = Address::generate(&env);
+    let blnd = env.register_stellar_asset_contract(admin.clone());
+    let usdc = env.register_stellar_asset_contract(admin.clone());
+
+    let new_admin = env.register_contract(None, MockAdminReceiver);
+    let new_admin_client = MockAdminReceiverClient::new(&env, &new_admin);
+
+    let owner = Address::generate(&env);
+    admin_transfer_client.initialize(&owner, &default_config(&env), &None);
+    admin_transfer_client.add_approved_admin(&new_admin);
+
+    let blend_fixture = BlendFixture::deploy(&env, &admin, &blnd, &usdc);
+    let pool = blend_fixture.pool_factory.deploy(
+        &admin,
+        &String::from_str(&env, "test"),
+        &BytesN::<32>::random(&env),
+        &Address::generate(&env),
+        &0,
+        &2,
+    );
+    let pool_2 = blend_fixture.pool_factory.deploy(
+        &admin,
+        &String::from_str(&env, "test_2"),
+        &BytesN::<32>::random(&env),
+        &Address::generate(&env),
+        &0,
+        &2,
+    );
+
+    admin_transfer_client.set_admin_transfer(
+        &pool,
+        &admin,
+        &new_admin,
+        &TransferOptions {
+            receive_hook: ReceiveHook::Fatal,
+            ..default_transfer_options(&env)
+        },
+    );
+    admin_transfer_client.set_admin_transfer(
+        &pool_2,
+        &admin,
+        &new_admin,
+        &TransferOptions {
+            receive_hook: ReceiveHook::Ignorable,
+            ..default_transfer_options(&env)
+        },
+    );
+
+    // validate a failing fatal hook reverts the transfer
+    new_admin_client.set_fail(&true);
+    let result = admin_transfer_client.try_transfer_admin(&pool);
+    assert!(result.is_err());
+    assert!(admin_transfer_client.get_admin_transfer(&pool).is_some());
+
+    // validate a failing ignorable hook does not revert the transfer
+    admin_transfer_client.transfer_admin(&pool_2);
+    assert!(admin_transfer_client.get_admin_transfer(&pool_2).is_none());
+    assert!(new_admin_client.received(&pool_2).is_none());
+
+    // validate the hook is invoked on a successful transfer
+    new_admin_client.set_fail(&false);
+    admin_transfer_client.transfer_admin(&pool);
+    assert!(admin_transfer_client.get_admin_transfer(&pool).is_none());
+    assert_eq!(new_admin_client.received(&pool), Some(admin.clone()));
+}
+
+#[test]
+fn test_admin_transfer_policy() {
+    let env = Env::default();
+    env.set_default_info();
+    env.mock_all_auths();
+
+    let admin_transfer_id = env.register_contract_wasm(None, admin_transfer_wasm::WASM);
+    let admin_transfer_client = AdminTransferClient::new(&env, &admin_transfer_id);
+
+    let admin = Address::generate(&env);
+    let blnd = env.register_stellar_asset_contract(admin.clone());
+    let usdc = env.register_stellar_asset_contract(admin.clone());
+
+    let new_admin = Address::generate(&env);
+    let options = default_transfer_options(&env);
+
+    let global_policy = env.register_contract(None, MockPolicy);
+    let global_policy_client = MockPolicyClient::new(&env, &global_policy);
+    let transfer_policy = env.register_contract(None, MockPolicy);
+    let transfer_policy_client = MockPolicyClient::new(&env, &transfer_policy);
+
+    let owner = Address::generate(&env);
+    admin_transfer_client.initialize(&owner, &default_config(&env), &None);
+    admin_transfer_client.add_approved_admin(&new_admin);
+
+    let blend_fixture = BlendFixture::deploy(&env, &admin, &blnd, &usdc);
+    let pool = blend_fixture.pool_factory.deploy(
+        &admin,
+        &String::from_str(&env, "test"),
+        &BytesN::<32>::random(&env),
+        &Address::generate(&env),
+        &0,
+        &2,
+    );
+    let pool_2 = blend_fixture.pool_factory.deploy(
+        &admin,
+        &String::from_str(&env, "test_2"),
+        &BytesN::<32>::random(&env),
+        &Address::generate(&env),
+        &0,
+        &2,
+    );
+
+    // set the global policy
+    admin_transfer_client.set_policy(&Some(global_policy.clone()));
+    assert_eq!(
+        admin_transfer_client.get_policy(),
+        Some(global_policy.clone())
+    );
+
+    // validate the global policy can veto creating an admin transfer
+    global_policy_client.set_reject(&TransferAction::Set, &true);
+    let result = admin_transfer_client.try_set_admin_transfer(&pool, &admin, &new_admin, &options);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::TransferRejectedByPolicy as u32
+        )))
+    );
+
+    // validate a transfer policy does not bypass the global policy
+    let transfer_options = TransferOptions {
+        policy: PolicySource::Contract(transfer_policy.clone()),
+        ..default_transfer_options(&env)
+    };
+    let result = admin_transfer_client.try_set_admin_transfer(
+        &pool_2,
+        &admin,
+        &new_admin,
+        &transfer_options,
+    );
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::TransferRejectedByPolicy as u32
+        )))
+    );
+
+    // validate a transfer policy can veto in addition to the global policy
+    global_policy_client.set_reject(&TransferAction::Set, &false);
+    transfer_policy_client.set_reject(&TransferAction::Set, &true);
+    let result = admin_transfer_client.try_set_admin_transfer(
+        &pool_2,
+        &admin,
+        &new_admin,
+        &transfer_options,
+    );
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::TransferRejectedByPolicy as u32
+        )))
+    );
+
+    transfer_policy_client.set_reject(&TransferAction::Set, &false);
+    admin_transfer_client.set_admin_transfer(&pool_2, &admin, &new_admin, &transfer_options);
+    let result = admin_transfer_client.get_admin_transfer(&pool_2).unwrap();
+    assert!(result.policy == PolicySource::Contract(transfer_policy.clone()));
+
+    admin_transfer_client.set_admin_transfer(&pool, &admin, &new_admin, &options);
+
+    // validate the global policy can veto cancelling an admin transfer
+    global_policy_client.set_reject(&TransferAction::Cancel, &true);
+    let result = admin_transfer_client.try_cancel_admin_transfer(&pool);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::TransferRejectedByPolicy as u32
+        )))
+    );
+
+    // validate the global policy can veto completing an admin transfer
+    global_policy_client.set_reject(&TransferAction::Transfer, &true);
+    let result = admin_transfer_client.try_transfer_admin(&pool);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::TransferRejectedByPolicy as u32
+        )))
+    );
+    assert!(admin_transfer_client.get_admin_transfer(&pool).is_some());
+
+    // validate removing the global policy allows the admin transfer to complete
+    admin_transfer_client.set_policy(&None);
+    admin_transfer_client.transfer_admin(&pool);
+    assert!(admin_transfer_client.get_admin_transfer(&pool).is_none());
+
+    admin_transfer_client.transfer_admin(&pool_2);
+    assert!(admin_transfer_client.get_admin_transfer(&pool_2).is_none());
+}
+
+#[test]
+fn test_admin_transfer_health_checks() {
+    let env = Env::default();
+    env.set_default_info();
+    env.mock_all_auths();
+
+    let admin_transfer_id = env.register_contract_wasm(None, admin_transfer_wasm::WASM);
+    let admin_transfer_client = AdminTransferClient::new(&env, &admin_transfer_id);
+
+    let admin = Address::generate(&env);
+    let blnd = env.register_stellar_asset_contract(admin.clone());
+    let usdc = env.register_stellar_asset_contract(admin.clone());
+
+    let new_admin = Address::generate(&env);
+
+    let owner = Address::generate(&env);
+    admin_transfer_client.initialize(&owner, &default_config(&env), &None);
+    admin_transfer_client.add_approved_admin(&new_admin);
+
+    let blend_fixture = BlendFixture::deploy(&env, &admin, &blnd, &usdc);
+    let pool = blend_fixture.pool_factory.deploy(
+        &admin,
+        &String::from_str(&env, "test"),
+        &BytesN::<32>::random(&env),
+        &Address::generate(&env),
+        &0,
+        &2,
+    );
+    let pool_status = get_pool_config(&env, &pool).status;
+
+    admin_transfer_client.set_admin_transfer(
+        &pool,
+        &admin,
+        &new_admin,
+        &TransferOptions {
+            pool_status,
+            ..default_transfer_options(&env)
+        },
+    );
+
+    // validate every failed health check is reported
+    let mut health_config = HealthConfig {
+        backstop: blend_fixture.backstop.address.clone(),
+        max_status: 0,
+        min_backstop_tokens: 1,
+        no_bad_debt: true,
+    };
+    admin_transfer_client.set_health_config(&Some(health_config.clone()));
+    assert_eq!(
+        admin_transfer_client.preview_transfer(&pool),
+        vec![
+            &env,
+            HealthFailure::PoolStatus(pool_status),
+            HealthFailure::BackstopTokens(0),
+        ]
+    );
+
+    // validate the admin transfer cannot complete with failed health checks
+    let result = admin_transfer_client.try_transfer_admin(&pool);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::PoolHealthCheckFailed as u32
+        )))
+    );
+    assert!(admin_transfer_client.get_admin_transfer(&pool).is_some());
+
+    // validate the admin transfer completes once the pool passes the health checks
+    health_config.max_status = pool_status;
+    health_config.min_backstop_tokens = 0;
+    admin_transfer_client.set_health_config(&Some(health_config));
+    assert_eq!(admin_transfer_client.preview_transfer(&pool).len(), 0);
+
+    admin_transfer_client.transfer_admin(&pool);
+    assert!(admin_transfer_client.get_admin_transfer(&pool).is_none());
+}
+
+#[test]
+fn test_admin_transfer_queued_reserves() {
+    let env = Env::default();
+    env.set_default_info();
+    env.mock_all_auths();
+
+    let admin_transfer_id = env.register_contract_wasm(None, admin_transfer_wasm::WASM);
+    let admin_transfer_client = AdminTransferClient::new(&env, &admin_transfer_id);
+
+    let admin = Address::generate(&env);
+    let blnd = env.register_stellar_asset_contract(admin.clone());
+    let usdc = env.register_stellar_asset_contract(admin.clone());
+
+    let new_admin = Address::generate(&env);
+
+    let owner = Address::generate(&env);
+    admin_transfer_client.initialize(&owner, &default_config(&env), &None);
+    admin_transfer_client.add_approved_admin(&new_admin);
+
+    let blend_fixture = BlendFixture::deploy(&env, &admin, &blnd, &usdc);
+    let pool = blend_fixture.pool_factory.deploy(
+        &admin,
+        &String::from_str(&env, "test"),
+        &BytesN::<32>::random(&env),
+        &Address::generate(&env),
+        &0,
+        &2,
+    );
+    let pool_client = PoolClient::new(&env, &pool);
+
+    // add a reserve to the pool and queue an update for it
+    let mut reserve_config = ReserveConfig {
+        index: 0,
+        decimals: 7,
+        c_factor: 9000000,
+        l_factor: 9000000,
+        util: 8000000,
+        max_util: 9500000,
+        r_base: 100000,
+        r_one: 500000,
+        r_two: 5000000,
+        r_three: 15000000,
+        reactivity: 20,
+    };
+    pool_client.queue_set_reserve(&usdc, &reserve_config);
+    env.jump(ONE_DAY_LEDGERS * 8);
+    pool_client.set_reserve(&usdc);
+
+    reserve_config.c_factor = 5000000;
+    pool_client.queue_set_reserve(&usdc, &reserve_config);
+
+    // validate an admin transfer refusing queued reserve updates cannot be created
+    let result = admin_transfer_client.try_set_admin_transfer(
+        &pool,
+        &admin,
+        &new_admin,
+        &TransferOptions {
+            queued_reserves: QueuedReserveMode::Refuse(vec![&env, usdc.clone()]),
+            ..default_transfer_options(&env)
+        },
+    );
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::QueuedReserveUpdate as u32
+        )))
+    );
+    assert!(admin_transfer_client.get_admin_transfer(&pool).is_none());
+
+    // validate an admin transfer cancelling queued reserve updates cancels them
+    admin_transfer_client.set_admin_transfer(
+        &pool,
+        &admin,
+        &new_admin,
+        &TransferOptions {
+            queued_reserves: QueuedReserveMode::Cancel(vec![&env, usdc.clone()]),
+            ..default_transfer_options(&env)
+        },
+    );
+    env.jump(ONE_DAY_LEDGERS * 8);
+    let result = pool_client.try_set_reserve(&usdc);
+    assert!(result.is_err());
+
+    admin_transfer_client.transfer_admin(&pool);
+    assert!(admin_transfer_client.get_admin_transfer(&pool).is_none());
+
+    // validate a queued initialization of a new reserve is detected if its asset is listed
+    reserve_config.index = 1;
+    pool_client.queue_set_reserve(&blnd, &reserve_config);
+    let next_admin = Address::generate(&env);
+    admin_transfer_client.add_approved_admin(&next_admin);
+    let result = admin_transfer_client.try_set_admin_transfer(
+        &pool,
+        &new_admin,
+        &next_admin,
+        &TransferOptions {
+            queued_reserves: QueuedReserveMode::Refuse(vec![&env, usdc.clone(), blnd.clone()]),
+            ..default_transfer_options(&env)
+        },
+    );
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::QueuedReserveUpdate as u32
+        )))
+    );
+
+    // validate a reserve update queued for an asset that is not listed is left in place
+    admin_transfer_client.set_admin_transfer(
+        &pool,
+        &new_admin,
+        &next_admin,
+        &TransferOptions {
+            queued_reserves: QueuedReserveMode::Refuse(vec![&env, usdc.clone()]),
+            ..default_transfer_options(&env)
+        },
+    );
+    admin_transfer_client.transfer_admin(&pool);
+    env.jump(ONE_DAY_LEDGERS * 8);
+    pool_client.set_reserve(&blnd);
+}
+
+#[test]
+fn test_admin_transfer_pool_actions() {
+    let env = Env::default();
+    env.set_default_info();
+    env.mock_all_auths();
+
+    let admin_transfer_id = env.register_contract_wasm(None, admin_transfer_wasm::WASM);
+    let admin_transfer_client = AdminTransferClient::new(&env, &admin_transfer_id);
+
+    let admin = Address::generate(&env);
+    let blnd = env.register_stellar_asset_contract(admin.clone());
+    let usdc = env.register_stellar_asset_contract(admin.clone());
+
+    let new_admin = Address::generate(&env);
+
+    let owner = Address::generate(&env);
+    admin_transfer_client.initialize(&owner, &default_config(&env), &None);
+    admin_transfer_client.add_approved_admin(&new_admin);
+
+    let blend_fixture = BlendFixture::deploy(&env, &admin, &blnd, &usdc);
+    let pool = blend_fixture.pool_factory.deploy(
+        &admin,
+        &String::from_str(&env, "test"),
+        &BytesN::<32>::random(&env),
+        &Address::generate(&env),
+        &0,
+        &2,
+    );
+
+    let actions = vec![
+        &env,
+        PoolAction::UpdatePool(1000000, 4),
+        PoolAction::SetStatus(4),
+    ];
+    admin_transfer_client.set_admin_transfer(
+        &pool,
+        &admin,
+        &new_admin,
+        &TransferOptions {
+            actions: actions.clone(),
+            ..default_transfer_options(&env)
+        },
+    );
+    let result = admin_transfer_client.get_admin_transfer(&pool).unwrap();
+    assert_eq!(result.actions, actions);
+
+    // -> validate the actions are not executed until the transfer completes
+    let pool_config = get_pool_config(&env, &pool);
+    assert_eq!(pool_config.bstop_rate, 0);
+    assert_eq!(pool_config.max_positions, 2);
+
+    // validate the health checks apply to the pool status set by the actions
+    admin_transfer_client.set_health_config(&Some(HealthConfig {
+        backstop: blend_fixture.backstop.address.clone(),
+        max_status: 2,
+        min_backstop_tokens: 0,
+        no_bad_debt: false,
+    }));
+    assert_eq!(
+        admin_transfer_client.preview_transfer(&pool),
+        vec![&env, HealthFailure::PoolStatus(4)]
+    );
+    let result = admin_transfer_client.try_transfer_admin(&pool);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::PoolHealthCheckFailed as u32
+        )))
+    );
+
+    admin_transfer_client.set_health_config(&None);
+    admin_transfer_client.transfer_admin(&pool);
+
+    // -> validate chain state
+    let pool_config = get_pool_config(&env, &pool);
+    assert_eq!(pool_config.bstop_rate, 1000000);
+    assert_eq!(pool_config.max_positions, 4);
+    assert_eq!(pool_config.status, 4);
+}
+
+#[test]
+fn test_admin_transfer_escrow_status() {
+    let env = Env::default();
+    env.set_default_info();
+    env.mock_all_auths();
+
+    let admin_transfer_id = env.register_contract_wasm(None, admin_transfer_wasm::WASM);
+    let admin_transfer_client = AdminTransferClient::new(&env, &admin_transfer_id);
+
+    let admin = Address::generate(&env);
+    let blnd = env.register_stellar_asset_contract(admin.clone());
+    let usdc = env.register_stellar_asset_contract(admin.clone());
+
+    let new_admin = Address::generate(&env);
+
+    let owner = Address::generate(&env);
+    admin_transfer_client.initialize(&owner, &default_config(&env), &None);
+    admin_transfer_client.add_approved_admin(&new_admin);
+
+    let blend_fixture = BlendFixture::deploy(&env, &admin, &blnd, &usdc);
+    let pool = blend_fixture.pool_factory.deploy(
+        &admin,
+        &String::from_str(&env, "test"),
+        &BytesN::<32>::random(&env),
+        &Address::generate(&env),
+        &0,
+        &2,
+    );
+    assert_eq!(get_pool_config(&env, &pool).status, 6);
+
+    // validate the pool status is set when escrow begins and the pre-escrow status is recorded
+    admin_transfer_client.set_admin_transfer(
+        &pool,
+        &admin,
+        &new_admin,
+        &TransferOptions {
+            escrow_status: EscrowStatus::Set(4, 2),
+            pool_status: 6,
+            ..default_transfer_options(&env)
+        },
+    );
+    assert_eq!(get_pool_config(&env, &pool).status, 4);
+    let result = admin_transfer_client.get_admin_transfer(&pool).unwrap();
+    assert_eq!(result.prev_status, 6);
+
+    // validate the admin status matching the pre-escrow setup status is restored when the
+    // transfer is cancelled
+    admin_transfer_client.cancel_admin_transfer(&pool);
+    assert_eq!(get_pool_config(&env, &pool).status, 2);
+
+    // validate the health checks are previewed against the status set when the transfer completes
+    admin_transfer_client.set_admin_transfer(
+        &pool,
+        &admin,
+        &new_admin,
+        &TransferOptions {
+            escrow_status: EscrowStatus::Set(4, 2),
+            pool_status: 6,
+            ..default_transfer_options(&env)
+        },
+    );
+    assert_eq!(get_pool_config(&env, &pool).status, 4);
+    admin_transfer_client.set_health_config(&Some(HealthConfig {
+        backstop: blend_fixture.backstop.address.clone(),
+        max_status: 2,
+        min_backstop_tokens: 0,
+        no_bad_debt: false,
+    }));
+    assert_eq!(admin_transfer_client.preview_transfer(&pool).len(), 0);
+
+    // validate the specified status is set when the transfer completes
+    admin_transfer_client.transfer_admin(&pool);
+    assert_eq!(get_pool_config(&env, &pool).status, 2);
+    assert!(admin_transfer_client.get_admin_transfer(&pool).is_none());
+}
+
+#[test]
+fn test_admin_transfer_stellar_asset() {
+    let env = Env::default();
+    env.set_default_info();
+    env.mock_all_auths();
+
+    let admin_transfer_id = env.register_contract_wasm(None, admin_transfer_wasm::WASM);
+    let admin_transfer_client = AdminTransferClient::new(&env, &admin_transfer_id);
+
+    let admin = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract(admin.clone());
+    let asset_client = StellarAssetClient::new(&env, &asset);
+
+    let new_admin = Address::generate(&env);
+
+    let owner = Address::generate(&env);
+    admin_transfer_client.initialize(&owner, &default_config(&env), &None);
+    admin_transfer_client.add_approved_admin(&new_admin);
+
+    let options = TransferOptions {
+        kind: TargetKind::StellarAsset,
+        ..default_transfer_options(&env)
+    };
+
+    // validate Blend pool only options cannot be used
+    let result = admin_transfer_client.try_set_admin_transfer(
+        &asset,
+        &admin,
+        &new_admin,
+        &TransferOptions {
+            escrow_status: EscrowStatus::Set(2, 0),
+            ..options.clone()
+        },
+    );
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::UnsupportedTargetOption as u32
+        )))
+    );
+
+    // create admin transfer
+    admin_transfer_client.set_admin_transfer(&asset, &admin, &new_admin, &options);
+
+    // -> validate chain state
+    let result = admin_transfer_client.get_admin_transfer(&asset).unwrap();
+    assert_eq!(result.kind, TargetKind::StellarAsset);
+    assert_eq!(asset_client.admin(), admin_transfer_id);
+
+    // cancel admin transfer
+    admin_transfer_client.cancel_admin_transfer(&asset);
+    assert_eq!(asset_client.admin(), admin);
+
+    // perform admin transfer
+    admin_transfer_client.set_admin_transfer(&asset, &admin, &new_admin, &options);
+    admin_transfer_client.transfer_admin(&asset);
+
+    // -> validate chain state
+    assert_eq!(asset_client.admin(), new_admin);
+    assert!(admin_transfer_client.get_admin_transfer(&asset).is_none());
+}
+
+#[test]
+fn test_admin_transfer_pool_escrow() {
+    let env = Env::default();
+    env.set_default_info();
+    env.mock_all_auths();
+
+    let admin_transfer_id = env.register_contract_wasm(None, admin_transfer_wasm::WASM);
+    let admin_transfer_client = AdminTransferClient::new(&env, &admin_transfer_id);
+
+    let admin = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract(admin.clone());
+    let asset_2 = env.register_stellar_asset_contract(admin.clone());
+
+    let new_admin = Address::generate(&env);
+
+    let owner = Address::generate(&env);
+    admin_transfer_client.initialize(&owner, &default_config(&env), &Some(asset.clone()));
+    admin_transfer_client.add_approved_admin(&new_admin);
+    assert_eq!(admin_transfer_client.get_pool(), Some(asset.clone()));
+
+    let options = TransferOptions {
+        kind: TargetKind::StellarAsset,
+        ..default_transfer_options(&env)
+    };
+
+    // validate an escrow for a pool cannot be used for another pool
+    let result =
+        admin_transfer_client.try_set_admin_transfer(&asset_2, &admin, &new_admin, &options);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::UnsupportedPool as u32
+        )))
+    );
+
+    // validate an escrow for a pool can be used for the pool
+    admin_transfer_client.set_admin_transfer(&asset, &admin, &new_admin, &options);
+    admin_transfer_client.transfer_admin(&asset);
+    assert_eq!(StellarAssetClient::new(&env, &asset).admin(), new_admin);
+}
+
+#[test]
+fn test_admin_transfer_two_step() {
+    let env = Env::default();
+    env.set_default_info();
+    env.mock_all_auths();
+
+    let admin_transfer_id = env.register_contract_wasm(None, admin_transfer_wasm::WASM);
+    let admin_transfer_client = AdminTransferClient::new(&env, &admin_transfer_id);
+
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+
+    let target = env.register_contract(None, MockTwoStepAdmin);
+    let target_client = MockTwoStepAdminClient::new(&env, &target);
+    target_client.initialize(&admin);
+
+    let owner = Address::generate(&env);
+    admin_transfer_client.initialize(&owner, &default_config(&env), &None);
+    admin_transfer_client.add_approved_admin(&new_admin);
+
+    let options = TransferOptions {
+        kind: TargetKind::TwoStep(
+            Symbol::new(&env, "propose_admin"),
+            Symbol::new(&env, "accept_admin"),
+        ),
+        ..default_transfer_options(&env)
+    };
+
+    // create admin transfer
+    admin_transfer_client.set_admin_transfer(&target, &admin, &new_admin, &options);
+    assert_eq!(target_client.admin(), admin_transfer_id);
+
+    // cancel admin transfer
+    admin_transfer_client.cancel_admin_transfer(&target);
+    assert_eq!(target_client.admin(), admin);
+
+    // perform admin transfer
+    admin_transfer_client.set_admin_transfer(&target, &admin, &new_admin, &options);
+    admin_transfer_client.transfer_admin(&target);
+
+    // -> validate the acceptance was authorized by the new admin
+    assert_eq!(
+        env.auths()[0],
+        (
+            new_admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    admin_transfer_id.clone(),
+                    Symbol::new(&env, "transfer_admin"),
+                    vec![&env, target.clone().into_val(&env)]
+                )),
+                sub_invocations: std::vec![AuthorizedInvocation {
+                    function: AuthorizedFunction::Contract((
+                        target.clone(),
+                        Symbol::new(&env, "accept_admin"),
+                        vec![&env]
+                    )),
+                    sub_invocations: std::vec![]
+                }]
+            }
+        )
+    );
+
+    // -> validate chain state
+    assert_eq!(target_client.admin(), new_admin);
+    assert!(admin_transfer_client.get_admin_transfer(&target).is_none());
+}
+
+#[test]
+fn test_guardian_cancel_two_step() {
+    let env = Env::default();
+    env.set_default_info();
+
+    let admin_transfer_id = env.register_contract_wasm(None, admin_transfer_wasm::WASM);
+    let admin_transfer_client = AdminTransferClient::new(&env, &admin_transfer_id);
+
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+
+    let target = env.register_contract(None, MockTwoStepAdmin);
+    let target_client = MockTwoStepAdminClient::new(&env, &target);
+    target_client.initialize(&admin);
+
+    let owner = Address::generate(&env);
+    let config = default_config(&env);
+    let guardian = config.guardian.clone();
+    env.mock_all_auths();
+    admin_transfer_client.initialize(&owner, &config, &None);
+    admin_transfer_client.add_approved_admin(&new_admin);
+
+    let options = TransferOptions {
+        kind: TargetKind::TwoStep(
+            Symbol::new(&env, "propose_admin"),
+            Symbol::new(&env, "accept_admin"),
+        ),
+        ..default_transfer_options(&env)
+    };
+    admin_transfer_client.set_admin_transfer(&target, &admin, &new_admin, &options);
+    assert_eq!(target_client.admin(), admin_transfer_id);
+
+    // guardian cancels the admin transfer with only its own authorization
+    admin_transfer_client
+        .mock_auths(&[MockAuth {
+            address: &guardian,
+            invoke: &MockAuthInvoke {
+                contract: &admin_transfer_id,
+                fn_name: &"guardian_cancel_admin_transfer",
+                args: vec![&env, target.clone().into_val(&env)],
+                sub_invokes: &[],
+            },
+        }])
+        .guardian_cancel_admin_transfer(&target);
+
+    // -> validate chain state
+    assert!(admin_transfer_client.get_admin_transfer(&target).is_none());
+    assert_eq!(target_client.admin(), admin_transfer_id);
+
+    // validate the current admin can accept the pending handover
+    target_client
+        .mock_auths(&[MockAuth {
+            address: &admin,
+            invoke: &MockAuthInvoke {
+                contract: &target,
+                fn_name: &"accept_admin",
+                args: vec![&env],
+                sub_invokes: &[],
+            },
+        }])
+        .accept_admin();
+    assert_eq!(target_client.admin(), admin);
+}
+
+#[test]
+fn test_deploy_and_set_admin_transfer() {
+    let env = Env::default();
+    env.set_default_info();
+    env.mock_all_auths();
+
+    let admin_transfer_id = env.register_contract_wasm(None, admin_transfer_wasm::WASM);
+    let admin_transfer_client = AdminTransferClient::new(&env, &admin_transfer_id);
+
+    let admin = Address::generate(&env);
+    let blnd = env.register_stellar_asset_contract(admin.clone());
+    let usdc = env.register_stellar_asset_contract(admin.clone());
+
+    let owner = Address::generate(&env);
+    admin_transfer_client.initialize(&owner, &default_config(&env), &None);
+
+    let blend_fixture = BlendFixture::deploy(&env, &admin, &blnd, &usdc);
+    let pool = blend_fixture.pool_factory.deploy(
+        &admin,
+        &String::from_str(&env, "test"),
+        &BytesN::<32>::random(&env),
+        &Address::generate(&env),
+        &0,
+        &2,
+    );
+
+    // use the admin transfer contract as the new admin contract
+    let wasm_hash = env
+        .deployer()
+        .upload_contract_wasm(admin_transfer_wasm::WASM);
+    let new_admin_owner = Address::generate(&env);
+    let deployment = Deployment {
+        wasm_hash: wasm_hash.clone(),
+        salt: BytesN::<32>::random(&env),
+        init_fn: Symbol::new(&env, "initialize"),
+        init_args: vec![
+            &env,
+            new_admin_owner.into_val(&env),
+            default_config(&env).into_val(&env),
+            Option::<Address>::None.into_val(&env),
+        ],
+    };
+
+    // validate the WASM hash must be approved
+    let result = admin_transfer_client.try_deploy_and_set_admin_transfer(
+        &pool,
+        &admin,
+        &deployment,
+        &default_transfer_options(&env),
+    );
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::WasmNotApproved as u32
+        )))
+    );
+
+    admin_transfer_client.add_approved_wasm(&wasm_hash);
+    assert_eq!(
+        admin_transfer_client.get_approved_wasms(),
+        vec![&env, wasm_hash.clone()]
+    );
+
+    // deploy the new admin contract and create the admin transfer
+    let new_admin = admin_transfer_client.deploy_and_set_admin_transfer(
+        &pool,
+        &admin,
+        &deployment,
+        &default_transfer_options(&env),
+    );
+
+    // -> validate chain state
+    let mut salt = admin.clone().to_xdr(&env);
+    salt.append(&pool.clone().to_xdr(&env));
+    salt.append(&deployment.salt.clone().into());
+    let expected_address = env
+        .deployer()
+        .with_address(admin_transfer_id.clone(), env.crypto().sha256(&salt))
+        .deployed_address();
+    assert_eq!(new_admin, expected_address);
+    let new_admin_client = AdminTransferClient::new(&env, &new_admin);
+    assert_eq!(new_admin_client.get_owner(), Some(new_admin_owner));
+    let result = admin_transfer_client.get_admin_transfer(&pool).unwrap();
+    assert_eq!(result.new_admin, NewAdmin::Address(new_admin.clone()));
+    assert_eq!(result.cur_admin, admin);
+
+    assert_eq!(
+        admin_transfer_client.get_deployment(&new_admin),
+        Some(wasm_hash.clone())
+    );
+
+    // validate the deployed contract can complete the admin transfer
+    admin_transfer_client.transfer_admin(&pool);
+    assert!(admin_transfer_client.get_admin_transfer(&pool).is_none());
+
+    // validate the deployed contract is a verified new admin for other pools
+    let pool_2 = blend_fixture.pool_factory.deploy(
+        &admin,
+        &String::from_str(&env, "test2"),
+        &BytesN::<32>::random(&env),
+        &Address::generate(&env),
+        &0,
+        &2,
+    );
+    let verified_options = TransferOptions {
+        allow_unverified: false,
+        ..default_transfer_options(&env)
+    };
+    admin_transfer_client.add_approved_admin(&new_admin);
+    admin_transfer_client.set_admin_transfer(&pool_2, &admin, &new_admin, &verified_options);
+    admin_transfer_client.cancel_admin_transfer(&pool_2);
+
+    // validate the deployed contract is no longer verified once its WASM hash is removed
+    admin_transfer_client.remove_approved_wasm(&wasm_hash);
+    let result = admin_transfer_client.try_set_admin_transfer(
+        &pool_2,
+        &admin,
+        &new_admin,
+        &verified_options,
+    );
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::NewAdminNotVerified as u32
+        )))
+    );
+}
+
+#[test]
+fn test_upgrade() {
+    let env = Env::default();
+    env.set_default_info();
+    env.mock_all_auths();
+
+    let admin_transfer_id = env.register_contract_wasm(None, admin_transfer_wasm::WASM);
+    let admin_transfer_client = AdminTransferClient::new(&env, &admin_transfer_id);
+
+    let owner = Address::generate(&env);
+    admin_transfer_client.initialize(&owner, &default_config(&env), &None);
+
+    let wasm_hash = env
+        .deployer()
+        .upload_contract_wasm(admin_transfer_wasm::WASM);
+
+    // validate an upgrade cannot be executed before one is queued
+    let result = admin_transfer_client.try_upgrade();
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::NoQueuedUpgrade as u32
+        )))
+    );
+
+    // queue an upgrade and cancel it
+    admin_transfer_client.queue_upgrade(&wasm_hash);
+    admin_transfer_client.cancel_upgrade();
+    assert!(admin_transfer_client.get_queued_upgrade().is_none());
+
+    // -> validate event
+    let events = env.events().all();
+    let event = vec![&env, events.get_unchecked(events.len() - 1)];
+    assert_eq!(
+        event,
+        vec![
+            &env,
+            (
+                admin_transfer_id.clone(),
+                (Symbol::new(&env, "cancel_upgrade"), owner.clone()).into_val(&env),
+                wasm_hash.clone().into_val(&env)
+            )
+        ]
+    );
+
+    // queue an upgrade
+    admin_transfer_client.queue_upgrade(&wasm_hash);
+    let upgrade = admin_transfer_client.get_queued_upgrade().unwrap();
+    assert_eq!(upgrade.wasm_hash, wasm_hash);
+    assert_eq!(upgrade.unlock_ledger, 100 + 7 * ONE_DAY_LEDGERS);
+
+    // validate the upgrade cannot be executed before the delay has passed
+    env.jump(7 * ONE_DAY_LEDGERS - 1);
+    let result = admin_transfer_client.try_upgrade();
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::UpgradeLocked as u32
+        )))
+    );
+
+    // validate the upgrade cannot be executed before the stored data is migrated
+    env.jump(1);
+    env.as_contract(&admin_transfer_id, || storage::set_storage_version(&env, 1));
+    let result = admin_transfer_client.try_upgrade();
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::MigrationRequired as u32
+        )))
+    );
+    env.as_contract(&admin_transfer_id, || {
+        storage::set_storage_version(&env, storage::STORAGE_VERSION)
+    });
+
+    // execute the upgrade
+    admin_transfer_client.upgrade();
+
+    // -> validate event
+    let events = env.events().all();
+    let event = vec![&env, events.get_unchecked(events.len() - 1)];
+    assert_eq!(
+        event,
+        vec![
+            &env,
+            (
+                admin_transfer_id.clone(),
+                (Symbol::new(&env, "upgrade"), owner.clone()).into_val(&env),
+                wasm_hash.clone().into_val(&env)
+            )
+        ]
+    );
+
+    // -> validate chain state
+    assert!(admin_transfer_client.get_queued_upgrade().is_none());
+    assert_eq!(admin_transfer_client.get_owner(), Some(owner));
+}
+
+#[test]
+fn test_migrate() {
+    let env = Env::default();
+    env.set_default_info();
+    env.mock_all_auths();
+
+    let admin_transfer_id = env.register_contract_wasm(None, admin_transfer_wasm::WASM);
+    let admin_transfer_client = AdminTransferClient::new(&env, &admin_transfer_id);
+
+    let owner = Address::generate(&env);
+    let pool = Address::generate(&env);
+    let pool_2 = Address::generate(&env);
+    let pool_3 = Address::generate(&env);
+    let cur_admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+
+    // store data with the storage version 1 layout, and an admin transfer that cannot be decoded
+    env.as_contract(&admin_transfer_id, || {
+        for pool in [pool.clone(), pool_2.clone()] {
+            env.storage().persistent().set(
+                &pool,
+                &LegacyTransferData {
+                    cur_admin: cur_admin.clone(),
+                    new_admin: new_admin.clone(),
+                },
+            );
+        }
+        env.storage().persistent().set(&pool_3, &1_u32);
+    });
+
+    // validate the pending admin transfer is not found and new admin transfers are refused
+    // until the contract is initialized
+    let result = admin_transfer_client.try_cancel_admin_transfer(&pool);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::NoAdminTransferExists as u32
+        )))
+    );
+    let result = admin_transfer_client.try_set_admin_transfer(
+        &Address::generate(&env),
+        &cur_admin,
+        &new_admin,
+        &default_transfer_options(&env),
+    );
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::MigrationRequired as u32
+        )))
+    );
+
+    // validate admin transfers cannot be migrated before the contract is initialized
+    let result = admin_transfer_client.try_migrate_admin_transfers(&vec![&env, pool.clone()]);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::MigrationRequired as u32
+        )))
+    );
+
+    admin_transfer_client.initialize(&owner, &default_config(&env), &None);
+    assert!(admin_transfer_client.get_admin_transfer(&pool).is_none());
+
+    // validate a pool with an admin transfer that is not migrated cannot be used
+    let result = admin_transfer_client.try_set_admin_transfer(
+        &pool_2,
+        &cur_admin,
+        &new_admin,
+        &default_transfer_options(&env),
+    );
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::MigrationRequired as u32
+        )))
+    );
+
+    // validate anyone can migrate admin transfers, leaving pool 2 out
+    admin_transfer_client
+        .mock_auths(&[])
+        .migrate_admin_transfers(&vec![&env, pool.clone()]);
+
+    // -> validate event
+    let events = env.events().all();
+    let event = vec![&env, events.get_unchecked(events.len() - 1)];
+    assert_eq!(
+        event,
+        vec![
+            &env,
+            (
+                admin_transfer_id.clone(),
+                (Symbol::new(&env, "migrate_transfer"), pool.clone()).into_val(&env),
+                ().into_val(&env)
+            )
+        ]
+    );
+
+    // -> validate chain state
+    let result = admin_transfer_client.get_admin_transfer(&pool).unwrap();
+    assert_eq!(result.kind, TargetKind::BlendPool);
+    assert_eq!(result.cur_admin, cur_admin);
+    assert_eq!(result.new_admin, NewAdmin::Address(new_admin.clone()));
+    assert_eq!(result.unlock_ledger, 0);
+    assert_eq!(result.expiration_ledger, u32::MAX);
+    assert_eq!(result.memo, String::from_str(&env, ""));
+    assert_eq!(result.proposal, ProposalReference::None);
+    assert_eq!(result.hashlock, Hashlock::None);
+    env.as_contract(&admin_transfer_id, || {
+        assert!(!env.storage().persistent().has(&pool));
+        assert!(env.storage().persistent().has(&pool_2));
+    });
+    assert!(admin_transfer_client.get_admin_transfer(&pool_2).is_none());
+
+    // validate a pool left out can be migrated later, and migrated pools and admin transfers that
+    // cannot be decoded are skipped
+    admin_transfer_client.migrate_admin_transfers(&vec![
+        &env,
+        pool_3.clone(),
+        pool.clone(),
+        pool_2.clone(),
+    ]);
+
+    // -> validate event
+    let events = env.events().all();
+    let event = vec![&env, events.get_unchecked(events.len() - 1)];
+    assert_eq!(
+        event,
+        vec![
+            &env,
+            (
+                admin_transfer_id.clone(),
+                (Symbol::new(&env, "migrate_transfer"), pool_2.clone()).into_val(&env),
+                ().into_val(&env)
+            )
+        ]
+    );
+
+    // -> validate chain state
+    let result = admin_transfer_client.get_admin_transfer(&pool_2).unwrap();
+    assert_eq!(result.new_admin, NewAdmin::Address(new_admin.clone()));
+    assert!(admin_transfer_client.get_admin_transfer(&pool).is_some());
+    assert!(admin_transfer_client.get_admin_transfer(&pool_3).is_none());
+    env.as_contract(&admin_transfer_id, || {
+        assert!(!env.storage().persistent().has(&pool_2));
+        assert!(env.storage().persistent().has(&pool_3));
+    });
+}
+
+#[test]
+fn test_version() {
+    let env = Env::default();
+
+    let admin_transfer_id = env.register_contract_wasm(None, admin_transfer_wasm::WASM);
+    let admin_transfer_client = AdminTransferClient::new(&env, &admin_transfer_id);
+
+    let cargo_version = include_str!("../Cargo.toml")
+        .lines()
+        .find(|line| line.starts_with("version"))
+        .and_then(|line| line.split('"').nth(1))
+        .unwrap();
+
+    // validate the reported version matches the crate version
+    let version = admin_transfer_client.version();
+    assert_eq!(version.version, String::from_str(&env, cargo_version));
+    assert!(version.features.contains(Symbol::new(&env, "upgrade")));
+
+    // validate the contract metadata matches the crate version
+    let meta = ScMetaEntry::ScMetaV0(ScMetaV0 {
+        key: "version".try_into().unwrap(),
+        val: cargo_version.try_into().unwrap(),
+    })
+    .to_xdr(Limits::none())
+    .unwrap();
+    assert!(admin_transfer_wasm::WASM
+        .windows(meta.len())
+        .any(|window| window == meta.as_slice()));
+}
+
+#[test]
+fn test_config() {
+    let env = Env::default();
+    env.set_default_info();
+
+    let admin_transfer_id = env.register_contract_wasm(None, admin_transfer_wasm::WASM);
+    let admin_transfer_client = AdminTransferClient::new(&env, &admin_transfer_id);
+
+    let owner = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let factory = Address::generate(&env);
+
+    // validate the default timelock must be less than the maximum expiry
+    let result = admin_transfer_client.try_initialize(
+        &owner,
+        &Config {
+            default_timelock: ONE_DAY_LEDGERS,
+            max_expiry: ONE_DAY_LEDGERS,
+            ..default_config(&env)
+        },
+        &None,
+    );
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::InvalidExpiry as u32
+        )))
+    );
+
+    admin_transfer_client.initialize(&owner, &default_config(&env), &None);
+
+    // validate only the owner can change the config
+    let result = admin_transfer_client
+        .mock_auths(&[MockAuth {
+            address: &guardian,
+            invoke: &MockAuthInvoke {
+                contract: &admin_transfer_id,
+                fn_name: &"set_guardian",
+                args: vec![&env, guardian.clone().into_val(&env)],
+                sub_invokes: &[],
+            },
+        }])
+        .try_set_guardian(&guardian);
+    assert!(result.is_err());
+
+    // change the config
+    env.mock_all_auths();
+    admin_transfer_client.set_default_timelock(&ONE_DAY_LEDGERS);
+    admin_transfer_client.set_max_expiry(&(7 * ONE_DAY_LEDGERS));
+    admin_transfer_client.set_trusted_factories(&vec![&env, factory.clone()]);
+    admin_transfer_client.set_guardian(&guardian);
+
+    // -> validate event
+    let config = admin_transfer_client.get_config().unwrap();
+    let events = env.events().all();
+    let event = vec![&env, events.get_unchecked(events.len() - 1)];
+    assert_eq!(
+        event,
+        vec![
+            &env,
+            (
+                admin_transfer_id.clone(),
+                (Symbol::new(&env, "set_config"), owner.clone()).into_val(&env),
+                config.clone().into_val(&env)
+            )
+        ]
+    );
+
+    // -> validate chain state
+    assert_eq!(config.default_timelock, ONE_DAY_LEDGERS);
+    assert_eq!(config.max_expiry, 7 * ONE_DAY_LEDGERS);
+    assert_eq!(config.trusted_factories, vec![&env, factory]);
+    assert_eq!(config.guardian, guardian);
+
+    // validate the maximum expiry cannot drop to the default timelock
+    let result = admin_transfer_client.try_set_max_expiry(&ONE_DAY_LEDGERS);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::InvalidExpiry as u32
+        )))
+    );
+}
+
+#[test]
+fn test_admin_transfer_timelock() {
+    let env = Env::default();
+    env.set_default_info();
+    env.mock_all_auths();
+
+    let admin_transfer_id = env.register_contract_wasm(None, admin_transfer_wasm::WASM);
+    let admin_transfer_client = AdminTransferClient::new(&env, &admin_transfer_id);
+
+    let admin = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract(admin.clone());
+    let asset_client = StellarAssetClient::new(&env, &asset);
+
+    let new_admin = Address::generate(&env);
+
+    let owner = Address::generate(&env);
+    let config = Config {
+        default_timelock: ONE_DAY_LEDGERS,
+        max_expiry: 7 * ONE_DAY_LEDGERS,
+        ..default_config(&env)
+    };
+    admin_transfer_client.initialize(&owner, &config, &None);
+    admin_transfer_client.add_approved_admin(&new_admin);
+
+    let options = TransferOptions {
+        kind: TargetKind::StellarAsset,
+        ..default_transfer_options(&env)
+    };
+
+    // validate the expiry cannot exceed the maximum expiry
+    let result = admin_transfer_client.try_set_admin_transfer(
+        &asset,
+        &admin,
+        &new_admin,
+        &TransferOptions {
+            expiry: 8 * ONE_DAY_LEDGERS,
+            ..options.clone()
+        },
+    );
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::InvalidExpiry as u32
+        )))
+    );
+
+    // create admin transfer with the default timelock and maximum expiry
+    admin_transfer_client.set_admin_transfer(&asset, &admin, &new_admin, &options);
+
+    // -> validate chain state
+    let result = admin_transfer_client.get_admin_transfer(&asset).unwrap();
+    assert_eq!(result.unlock_ledger, 100 + ONE_DAY_LEDGERS);
+    assert_eq!(result.expiration_ledger, 100 + 7 * ONE_DAY_LEDGERS);
+
+    // validate the admin transfer is locked until the timelock passes
+    env.jump(ONE_DAY_LEDGERS - 1);
+    let result = admin_transfer_client.try_transfer_admin(&asset);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::AdminTransferLocked as u32
+        )))
+    );
+
+    // validate the admin transfer cannot complete once expired
+    env.jump(6 * ONE_DAY_LEDGERS + 1);
+    let result = admin_transfer_client.try_transfer_admin(&asset);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::AdminTransferExpired as u32
+        )))
+    );
+
+    // guardian cancels the expired admin transfer
+    admin_transfer_client.guardian_cancel_admin_transfer(&asset);
+
+    // -> validate event
+    let events = env.events().all();
+    let event = vec![&env, events.get_unchecked(events.len() - 1)];
+    assert_eq!(
+        event,
+        vec![
+            &env,
+            (
+                admin_transfer_id.clone(),
+                (
+                    Symbol::new(&env, "guardian_cancel"),
+                    config.guardian.clone()
+                )
+                    .into_val(&env),
+                asset.clone().into_val(&env)
+            )
+        ]
+    );
+
+    // -> validate chain state
+    assert_eq!(asset_client.admin(), admin);
+    assert!(admin_transfer_client.get_admin_transfer(&asset).is_none());
+
+    // perform admin transfer with its own timelock
+    admin_transfer_client.set_admin_transfer(
+        &asset,
+        &admin,
+        &new_admin,
+        &TransferOptions {
+            timelock: 10,
+            ..options.clone()
+        },
+    );
+    env.jump(10);
+    admin_transfer_client.transfer_admin(&asset);
+
+    // -> validate chain state
+    assert_eq!(asset_client.admin(), new_admin);
+}
+
+#[test]
+fn test_owner_transfer() {
+    let env = Env::default();
+    env.set_default_info();
+
+    let admin_transfer_id = env.register_contract_wasm(None, admin_transfer_wasm::WASM);
+    let admin_transfer_client = AdminTransferClient::new(&env, &admin_transfer_id);
+
+    let owner = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+    let sauron = Address::generate(&env);
+
+    admin_transfer_client.initialize(&owner, &default_config(&env), &None);
+
+    // validate only the owner can propose a new owner
+    let result = admin_transfer_client
+        .mock_auths(&[MockAuth {
+            address: &sauron,
+            invoke: &MockAuthInvoke {
+                contract: &admin_transfer_id,
+                fn_name: &"propose_owner",
+                args: vec![&env, sauron.clone().into_val(&env)],
+                sub_invokes: &[],
+            },
+        }])
+        .try_propose_owner(&sauron);
+    assert!(result.is_err());
+
+    env.mock_all_auths();
+
+    // propose a new owner and cancel the proposal
+    admin_transfer_client.propose_owner(&sauron);
+    admin_transfer_client.cancel_owner_proposal();
+
+    // -> validate event
+    let events = env.events().all();
+    let event = vec![&env, events.get_unchecked(events.len() - 1)];
+    assert_eq!(
+        event,
+        vec![
+            &env,
+            (
+                admin_transfer_id.clone(),
+                (Symbol::new(&env, "cancel_owner_proposal"), owner.clone()).into_val(&env),
+                sauron.clone().into_val(&env)
+            )
+        ]
+    );
+    assert!(admin_transfer_client.get_owner_proposal().is_none());
+
+    // validate the proposal expires
+    admin_transfer_client.propose_owner(&new_owner);
+    let proposal = admin_transfer_client.get_owner_proposal().unwrap();
+    assert_eq!(proposal.new_owner, new_owner);
+    assert_eq!(proposal.expiration_ledger, 100 + 30 * ONE_DAY_LEDGERS);
+
+    // validate a second proposal cannot be made while one is pending
+    let result = admin_transfer_client.try_propose_owner(&sauron);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::OwnerProposalExists as u32
+        )))
+    );
+
+    env.jump(30 * ONE_DAY_LEDGERS);
+    let result = admin_transfer_client.try_accept_owner();
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::OwnerProposalExpired as u32
+        )))
+    );
+
+    // validate an expired proposal is replaced by a new proposal
+    admin_transfer_client.propose_owner(&sauron);
+    let proposal = admin_transfer_client.get_owner_proposal().unwrap();
+    assert_eq!(proposal.new_owner, sauron);
+    assert_eq!(proposal.expiration_ledger, 100 + 60 * ONE_DAY_LEDGERS);
+
+    // validate an expired proposal can be cancelled
+    env.jump(30 * ONE_DAY_LEDGERS);
+    admin_transfer_client.cancel_owner_proposal();
+
+    // propose a new owner and accept ownership
+    admin_transfer_client.propose_owner(&new_owner);
+    admin_transfer_client.accept_owner();
+    assert_eq!(
+        env.auths()[0],
+        (
+            new_owner.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    admin_transfer_id.clone(),
+                    Symbol::new(&env, "accept_owner"),
+                    vec![&env]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+
+    // -> validate event
+    let events = env.events().all();
+    let event = vec![&env, events.get_unchecked(events.len() - 1)];
+    assert_eq!(
+        event,
+        vec![
+            &env,
+            (
+                admin_transfer_id.clone(),
+                (Symbol::new(&env, "accept_owner"), new_owner.clone()).into_val(&env),
+                owner.clone().into_val(&env)
+            )
+        ]
+    );
+
+    // -> validate chain state
+    assert_eq!(admin_transfer_client.get_owner(), Some(new_owner));
+    assert!(admin_transfer_client.get_owner_proposal().is_none());
+}
+
+#[test]
+fn test_admin_transfer_cooldown() {
+    let env = Env::default();
+    env.set_default_info();
+    env.mock_all_auths();
+
+    let admin_transfer_id = env.register_contract_wasm(None, admin_transfer_wasm::WASM);
+    let admin_transfer_client = AdminTransferClient::new(&env, &admin_transfer_id);
+
+    let admin = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract(admin.clone());
+    let asset_client = StellarAssetClient::new(&env, &asset);
+
+    let new_admin = Address::generate(&env);
+
+    let owner = Address::generate(&env);
+    admin_transfer_client.initialize(&owner, &default_config(&env), &None);
+    admin_transfer_client.add_approved_admin(&new_admin);
+    admin_transfer_client.add_approved_admin(&admin);
+    admin_transfer_client.set_cooldown(&(3 * ONE_DAY_LEDGERS));
+    assert_eq!(
+        admin_transfer_client.get_config().unwrap().cooldown,
+        3 * ONE_DAY_LEDGERS
+    );
+
+    let options = TransferOptions {
+        kind: TargetKind::StellarAsset,
+        ..default_transfer_options(&env)
+    };
+
+    // validate a cancelled admin transfer does not start a cooldown
+    admin_transfer_client.set_admin_transfer(&asset, &admin, &new_admin, &options);
+    admin_transfer_client.cancel_admin_transfer(&asset);
+    assert_eq!(admin_transfer_client.get_cooldown(&asset), 0);
+
+    // perform admin transfer
+    admin_transfer_client.set_admin_transfer(&asset, &admin, &new_admin, &options);
+    admin_transfer_client.transfer_admin(&asset);
+
+    // -> validate chain state
+    assert_eq!(asset_client.admin(), new_admin);
+    assert_eq!(
+        admin_transfer_client.get_cooldown(&asset),
+        100 + 3 * ONE_DAY_LEDGERS
+    );
+
+    // validate the pool cannot be transferred again during the cooldown
+    env.jump(3 * ONE_DAY_LEDGERS - 1);
+    let result = admin_transfer_client.try_set_admin_transfer(&asset, &new_admin, &admin, &options);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::PoolInCooldown as u32
+        )))
+    );
+
+    // transfer the pool back after the cooldown
+    env.jump(1);
+    admin_transfer_client.set_admin_transfer(&asset, &new_admin, &admin, &options);
+    admin_transfer_client.transfer_admin(&asset);
+
+    // -> validate chain state
+    assert_eq!(asset_client.admin(), admin);
+}
+
+#[test]
+fn test_admin_transfer_memo() {
+    let env = Env::default();
+    env.set_default_info();
+    env.mock_all_auths();
+
+    let admin_transfer_id = env.register_contract_wasm(None, admin_transfer_wasm::WASM);
+    let admin_transfer_client = AdminTransferClient::new(&env, &admin_transfer_id);
+
+    let admin = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract(admin.clone());
+
+    let new_admin = Address::generate(&env);
+    let governor = Address::generate(&env);
+
+    let owner = Address::generate(&env);
+    admin_transfer_client.initialize(&owner, &default_config(&env), &None);
+    admin_transfer_client.add_approved_admin(&new_admin);
+
+    let memo = String::from_str(&env, "Hand the pool over to the DAO");
+    let proposal = ProposalReference::Governor(governor.clone(), 12);
+    let options = TransferOptions {
+        kind: TargetKind::StellarAsset,
+        memo: memo.clone(),
+        proposal: proposal.clone(),
+        ..default_transfer_options(&env)
+    };
+
+    // validate the memo length is limited
+    let result = admin_transfer_client.try_set_admin_transfer(
+        &asset,
+        &admin,
+        &new_admin,
+        &TransferOptions {
+            memo: String::from_str(
+                &env,
+                "This memo is far too long to be stored with an admin transfer on chain",
+            ),
+            ..options.clone()
+        },
+    );
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::MemoTooLong as u32
+        )))
+    );
+
+    // create admin transfer
+    admin_transfer_client.set_admin_transfer(&asset, &admin, &new_admin, &options);
+
+    // -> validate event
+    let events = env.events().all();
+    let event = vec![&env, events.get_unchecked(events.len() - 1)];
+    assert_eq!(
+        event,
+        vec![
+            &env,
+            (
+                admin_transfer_id.clone(),
+                (Symbol::new(&env, "set_admin_transfer"), asset.clone()).into_val(&env),
+                (
+                    admin.clone(),
+                    new_admin.clone(),
+                    memo.clone(),
+                    proposal.clone()
+                )
+                    .into_val(&env)
+            )
+        ]
+    );
+
+    // -> validate chain state
+    let result = admin_transfer_client.get_admin_transfer(&asset).unwrap();
+    assert_eq!(result.memo, memo);
+    assert_eq!(result.proposal, proposal);
+
+    // perform admin transfer
+    admin_transfer_client.transfer_admin(&asset);
+
+    // -> validate event
+    let events = env.events().all();
+    let event = vec![&env, events.get_unchecked(events.len() - 1)];
+    assert_eq!(
+        event,
+        vec![
+            &env,
+            (
+                admin_transfer_id.clone(),
+                (Symbol::new(&env, "transfer_admin"), asset.clone()).into_val(&env),
+                (
+                    admin.clone(),
+                    new_admin.clone(),
+                    memo,
+                    proposal,
+                    None::<Bytes>
+                )
+                    .into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+fn test_admin_transfer_bind_proposal() {
+    let env = Env::default();
+    env.set_default_info();
+    env.mock_all_auths();
+
+    let admin_transfer_id = env.register_contract_wasm(None, admin_transfer_wasm::WASM);
+    let admin_transfer_client = AdminTransferClient::new(&env, &admin_transfer_id);
+
+    let admin = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract(admin.clone());
+    let asset_client = StellarAssetClient::new(&env, &asset);
+
+    let governor = env.register_contract(None, MockGovernor);
+    let governor_client = MockGovernorClient::new(&env, &governor);
+
+    let owner = Address::generate(&env);
+    admin_transfer_client.initialize(&owner, &default_config(&env), &None);
+    admin_transfer_client.add_approved_admin(&governor);
+
+    let options = TransferOptions {
+        kind: TargetKind::StellarAsset,
+        proposal: ProposalReference::Governor(governor.clone(), 7),
+        bind_proposal: true,
+        ..default_transfer_options(&env)
+    };
+
+    // validate the bound proposal must be on the new admin
+    let result = admin_transfer_client.try_set_admin_transfer(
+        &asset,
+        &admin,
+        &governor,
+        &TransferOptions {
+            proposal: ProposalReference::Governor(Address::generate(&env), 7),
+            ..options.clone()
+        },
+    );
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::InvalidProposalBinding as u32
+        )))
+    );
+
+    // validate the bound proposal must exist and complete the admin transfer
+    let result = admin_transfer_client.try_set_admin_transfer(&asset, &admin, &governor, &options);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::InvalidProposalBinding as u32
+        )))
+    );
+    governor_client.set_proposal(
+        &7,
+        &admin_transfer_id,
+        &Symbol::new(&env, "transfer_admin"),
+        &vec![&env, Address::generate(&env).into_val(&env)],
+    );
+    let result = admin_transfer_client.try_set_admin_transfer(&asset, &admin, &governor, &options);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::InvalidProposalBinding as u32
+        )))
+    );
+
+    // validate the bound proposal must be open or successful
+    governor_client.set_proposal(
+        &7,
+        &admin_transfer_id,
+        &Symbol::new(&env, "transfer_admin"),
+        &vec![&env, asset.clone().into_val(&env)],
+    );
+    governor_client.set_proposal_status(&7, &ProposalStatus::Defeated);
+    let result = admin_transfer_client.try_set_admin_transfer(&asset, &admin, &governor, &options);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::InvalidProposalBinding as u32
+        )))
+    );
+
+    // create admin transfer
+    governor_client.set_proposal_status(&7, &ProposalStatus::Successful);
+    admin_transfer_client.set_admin_transfer(&asset, &admin, &governor, &options);
+
+    // validate the admin transfer cannot complete without the governor's authorization
+    let result = admin_transfer_client
+        .mock_auths(&[])
+        .try_transfer_admin(&asset);
+    assert!(result.is_err());
+
+    // perform admin transfer by executing the proposal
+    governor_client.mock_auths(&[]).execute(&7);
+
+    // -> validate chain state
+    assert_eq!(asset_client.admin(), governor);
+    assert!(admin_transfer_client.get_admin_transfer(&asset).is_none());
+}
+
+#[test]
+fn test_admin_transfer_bond() {
+    let env = Env::default();
+    env.set_default_info();
+    env.mock_all_auths();
+
+    let admin_transfer_id = env.register_contract_wasm(None, admin_transfer_wasm::WASM);
+    let admin_transfer_client = AdminTransferClient::new(&env, &admin_transfer_id);
+
+    let admin = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract(admin.clone());
+
+    let bond_token = env.register_stellar_asset_contract(Address::generate(&env));
+    let bond_token_client = TokenClient::new(&env, &bond_token);
+    StellarAssetClient::new(&env, &bond_token).mint(&admin, &1000_0000000);
+
+    let new_admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    let owner = Address::generate(&env);
+    admin_transfer_client.initialize(&owner, &default_config(&env), &None);
+    admin_transfer_client.add_approved_admin(&new_admin);
+
+    let options = TransferOptions {
+        kind: TargetKind::StellarAsset,
+        ..default_transfer_options(&env)
+    };
+
+    // validate the forfeit cannot exceed the bond
+    let bond_config = BondConfig {
+        token: bond_token.clone(),
+        amount: 100_0000000,
+        forfeit_bps: 2500,
+        treasury: treasury.clone(),
+    };
+    let result = admin_transfer_client.try_set_bond_config(&Some(BondConfig {
+        forfeit_bps: 10001,
+        ..bond_config.clone()
+    }));
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::InvalidBondConfig as u32
+        )))
+    );
+
+    admin_transfer_client.set_bond_config(&Some(bond_config));
+
+    // create admin transfer
+    admin_transfer_client.set_admin_transfer(&asset, &admin, &new_admin, &options);
+
+    // -> validate chain state
+    assert_eq!(bond_token_client.balance(&admin), 900_0000000);
+    assert_eq!(bond_token_client.balance(&admin_transfer_id), 100_0000000);
+    let bond = admin_transfer_client.get_bond(&asset).unwrap();
+    assert_eq!(bond.amount, 100_0000000);
+    assert_eq!(bond.treasury, treasury);
+    let totals = admin_transfer_client.get_bond_totals(&bond_token);
+    assert_eq!(totals.held, 100_0000000);
+
+    // validate part of the bond is forfeited when the admin transfer is cancelled
+    admin_transfer_client.cancel_admin_transfer(&asset);
+    assert_eq!(bond_token_client.balance(&admin), 975_0000000);
+    assert_eq!(bond_token_client.balance(&treasury), 25_0000000);
+    assert_eq!(bond_token_client.balance(&admin_transfer_id), 0);
+    assert!(admin_transfer_client.get_bond(&asset).is_none());
+    let totals = admin_transfer_client.get_bond_totals(&bond_token);
+    assert_eq!(totals.held, 0);
+    assert_eq!(totals.returned, 75_0000000);
+    assert_eq!(totals.forfeited, 25_0000000);
+
+    // validate the bond is returned when the admin transfer completes
+    admin_transfer_client.set_admin_transfer(&asset, &admin, &new_admin, &options);
+    assert_eq!(bond_token_client.balance(&admin), 875_0000000);
+    admin_transfer_client.transfer_admin(&asset);
+    assert_eq!(bond_token_client.balance(&admin), 975_0000000);
+    assert_eq!(bond_token_client.balance(&admin_transfer_id), 0);
+    let totals = admin_transfer_client.get_bond_totals(&bond_token);
+    assert_eq!(totals.held, 0);
+    assert_eq!(totals.returned, 175_0000000);
+    assert_eq!(totals.forfeited, 25_0000000);
+}
+
+#[test]
+fn test_admin_transfer_hashlock() {
+    let env = Env::default();
+    env.set_default_info();
+    env.mock_all_auths();
+
+    let admin_transfer_id = env.register_contract_wasm(None, admin_transfer_wasm::WASM);
+    let admin_transfer_client = AdminTransferClient::new(&env, &admin_transfer_id);
+
+    let admin = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract(admin.clone());
+    let asset_client = StellarAssetClient::new(&env, &asset);
+
+    let new_admin = Address::generate(&env);
+
+    let owner = Address::generate(&env);
+    admin_transfer_client.initialize(&owner, &default_config(&env), &None);
+    admin_transfer_client.add_approved_admin(&new_admin);
+
+    let preimage = Bytes::from_slice(&env, b"admin transfer secret");
+    let options = TransferOptions {
+        kind: TargetKind::StellarAsset,
+        hashlock: Hashlock::Sha256(env.crypto().sha256(&preimage)),
+        ..default_transfer_options(&env)
+    };
+
+    // create admin transfer
+    admin_transfer_client.set_admin_transfer(&asset, &admin, &new_admin, &options);
+
+    // -> validate chain state
+    let result = admin_transfer_client.get_admin_transfer(&asset).unwrap();
+    assert_eq!(result.hashlock, options.hashlock);
+
+    // validate the preimage is required
+    let result = admin_transfer_client.try_transfer_admin(&asset);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::PreimageRequired as u32
+        )))
+    );
+
+    // validate the preimage must match the hashlock
+    let result = admin_transfer_client
+        .try_transfer_admin_with_preimage(&asset, &Bytes::from_slice(&env, b"wrong secret"));
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::InvalidPreimage as u32
+        )))
+    );
+
+    // perform admin transfer
+    admin_transfer_client.transfer_admin_with_preimage(&asset, &preimage);
+
+    // -> validate event
+    let events = env.events().all();
+    let event = vec![&env, events.get_unchecked(events.len() - 1)];
+    assert_eq!(
+        event,
+        vec![
+            &env,
+            (
+                admin_transfer_id.clone(),
+                (Symbol::new(&env, "transfer_admin"), asset.clone()).into_val(&env),
+                (
+                    admin.clone(),
+                    new_admin.clone(),
+                    String::from_str(&env, ""),
+                    ProposalReference::None,
+                    Some(preimage)
+                )
+                    .into_val(&env)
+            )
+        ]
+    );
+
+    // -> validate chain state
+    assert_eq!(asset_client.admin(), new_admin);
+    assert!(admin_transfer_client.get_admin_transfer(&asset).is_none());
+}
+
+#[test]
+fn test_admin_transfer_commit_reveal() {
+    let env = Env::default();
+    env.set_default_info();
+    env.mock_all_auths();
+
+    let admin_transfer_id = env.register_contract_wasm(None, admin_transfer_wasm::WASM);
+    let admin_transfer_client = AdminTransferClient::new(&env, &admin_transfer_id);
+
+    let admin = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract(admin.clone());
+    let asset_client = StellarAssetClient::new(&env, &asset);
+
+    let new_admin = Address::generate(&env);
+
+    let owner = Address::generate(&env);
+    admin_transfer_client.initialize(&owner, &default_config(&env), &None);
+    admin_transfer_client.add_approved_admin(&new_admin);
+
+    let salt = BytesN::<32>::random(&env);
+    let mut preimage = new_admin.clone().to_xdr(&env);
+    preimage.append(&salt.clone().into());
+    let commitment = env.crypto().sha256(&preimage);
+    let options = TransferOptions {
+        kind: TargetKind::StellarAsset,
+        ..default_transfer_options(&env)
+    };
+
+    // create admin transfer
+    admin_transfer_client.set_committed_admin_transfer(&asset, &admin, &commitment, &options);
+
+    // -> validate event
+    let events = env.events().all();
+    let event = vec![&env, events.get_unchecked(events.len() - 1)];
+    assert_eq!(
+        event,
+        vec![
+            &env,
+            (
+                admin_transfer_id.clone(),
+                (Symbol::new(&env, "set_admin_transfer"), asset.clone()).into_val(&env),
+                (
+                    admin.clone(),
+                    Option::<Address>::None,
+                    String::from_str(&env, ""),
+                    ProposalReference::None
+                )
+                    .into_val(&env)
+            )
+        ]
+    );
+
+    // -> validate chain state
+    let result = admin_transfer_client.get_admin_transfer(&asset).unwrap();
+    assert_eq!(
+        result.new_admin,
+        NewAdmin::Committed(commitment, true, false)
+    );
+    assert_eq!(asset_client.admin(), admin_transfer_id);
+
+    // validate the admin transfer cannot complete before the new admin is revealed
+    let result = admin_transfer_client.try_transfer_admin(&asset);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::NewAdminNotRevealed as u32
+        )))
+    );
+
+    // validate the new admin and salt must match the commitment
+    let result =
+        admin_transfer_client.try_reveal_new_admin(&asset, &new_admin, &BytesN::random(&env));
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::InvalidCommitment as u32
+        )))
+    );
+
+    // reveal new admin
+    admin_transfer_client.reveal_new_admin(&asset, &new_admin, &salt);
+
+    // -> validate event
+    let events = env.events().all();
+    let event = vec![&env, events.get_unchecked(events.len() - 1)];
+    assert_eq!(
+        event,
+        vec![
+            &env,
+            (
+                admin_transfer_id.clone(),
+                (Symbol::new(&env, "reveal_new_admin"), asset.clone()).into_val(&env),
+                new_admin.into_val(&env)
+            )
+        ]
+    );
+
+    // -> validate chain state
+    let result = admin_transfer_client.get_admin_transfer(&asset).unwrap();
+    assert_eq!(result.new_admin, NewAdmin::Address(new_admin.clone()));
+
+    // validate the new admin cannot be revealed twice
+    let result = admin_transfer_client.try_reveal_new_admin(&asset, &new_admin, &salt);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::NewAdminNotCommitted as u32
+        )))
+    );
+
+    // perform admin transfer
+    admin_transfer_client.transfer_admin(&asset);
+
+    // -> validate chain state
+    assert_eq!(asset_client.admin(), new_admin);
+    assert!(admin_transfer_client.get_admin_transfer(&asset).is_none());
+}
+
+#[test]
+fn test_admin_transfer_candidates() {
+    let env = Env::default();
+    env.set_default_info();
+    env.mock_all_auths();
+
+    let admin_transfer_id = env.register_contract_wasm(None, admin_transfer_wasm::WASM);
+    let admin_transfer_client = AdminTransferClient::new(&env, &admin_transfer_id);
+
+    let admin = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract(admin.clone());
+    let asset_client = StellarAssetClient::new(&env, &asset);
+
+    let candidate_0 = Address::generate(&env);
+    let candidate_1 = Address::generate(&env);
+    let not_candidate = Address::generate(&env);
+
+    let owner = Address::generate(&env);
+    admin_transfer_client.initialize(&owner, &default_config(&env), &None);
+    admin_transfer_client.add_approved_admin(&candidate_0);
+    admin_transfer_client.add_approved_admin(&candidate_1);
+    admin_transfer_client.add_approved_admin(&not_candidate);
+
+    let candidates = vec![&env, candidate_0.clone(), candidate_1.clone()];
+    let options = TransferOptions {
+        kind: TargetKind::StellarAsset,
+        ..default_transfer_options(&env)
+    };
+
+    // validate candidates cannot be duplicated
+    let result = admin_transfer_client.try_set_candidates_admin_transfer(
+        &asset,
+        &admin,
+        &vec![&env, candidate_0.clone(), candidate_0.clone()],
+        &options,
+    );
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::InvalidCandidates as u32
+        )))
+    );
+
+    // create admin transfer
+    admin_transfer_client.set_candidates_admin_transfer(&asset, &admin, &candidates, &options);
+
+    // -> validate event
+    let events = env.events().all();
+    let event = vec![&env, events.get_unchecked(events.len() - 1)];
+    assert_eq!(
+        event,
+        vec![
+            &env,
+            (
+                admin_transfer_id.clone(),
+                (Symbol::new(&env, "set_admin_transfer"), asset.clone()).into_val(&env),
+                (
+                    admin.clone(),
+                    Option::<Address>::None,
+                    String::from_str(&env, ""),
+                    ProposalReference::None
+                )
+                    .into_val(&env)
+            )
+        ]
+    );
+
+    // -> validate chain state
+    let result = admin_transfer_client.get_admin_transfer(&asset).unwrap();
+    assert_eq!(result.new_admin, NewAdmin::Candidates(candidates));
+    assert_eq!(asset_client.admin(), admin_transfer_id);
+
+    // validate a candidate must accept the admin transfer
+    let result = admin_transfer_client.try_transfer_admin(&asset);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::CandidateRequired as u32
+        )))
+    );
+    let result = admin_transfer_client.try_transfer_admin_to_candidate(&asset, &not_candidate);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_contract_error(
+            ContractError::NotACandidate as u32
+        )))
+    );
+
+    // perform admin transfer
+    admin_transfer_client.transfer_admin_to_candidate(&asset, &candidate_1);
+    assert_eq!(
+        env.auths()[0],
+        (
+            candidate_1.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    admin_transfer_id.clone(),
+                    Symbol::new(&env, "transfer_admin_to_candidate"),
+                    vec![
+                        &env,
+                        asset.clone().into_val(&env),
+                        candidate_1.clone().into_val(&env),
+                    ]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+
+    // -> validate events
+    let events = env.events().all();
+    let event = vec![
+        &env,
+        events.get_unchecked(events.len() - 2),
+        events.get_unchecked(events.len() - 1),
+    ];
+    assert_eq!(
+        event,
+        vec![
+            &env,
+            (
+                admin_transfer_id.clone(),
+                (Symbol::new(&env, "transfer_admin"), asset.clone()).into_val(&env),
+                (
+                    admin.clone(),
+                    candidate_1.clone(),
+                    String::from_str(&env, ""),
+                    ProposalReference::None,
+                    None::<Bytes>
+                )
+                    .into_val(&env)
+            ),
+            (
+                admin_transfer_id.clone(),
+                (Symbol::new(&env, "reject_candidate"), candidate_0.clone()).into_val(&env),
+                (asset.clone(), candidate_1.clone()).into_val(&env)
+            )
+        ]
+    );
+
+    // -> validate chain state
+    assert_eq!(asset_client.admin(), candidate_1);
+    assert!(admin_transfer_client.get_admin_transfer(&asset).is_none());
+}
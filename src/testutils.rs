@@ -1,9 +1,19 @@
 #![cfg(test)]
 
-use crate::storage::ONE_DAY_LEDGERS;
+use crate::{
+    adapter::TargetKind,
+    governor::{Calldata, Proposal, ProposalAction, ProposalConfig, ProposalData, ProposalStatus},
+    policy::TransferAction,
+    storage::{
+        Config, EscrowStatus, Hashlock, PolicySource, ProposalReference, QueuedReserveMode,
+        ReceiveHook, TransferOptions, ONE_DAY_LEDGERS,
+    },
+};
+use blend_contract_sdk::pool::PoolConfig;
 use soroban_sdk::{
-    testutils::{Ledger as _, LedgerInfo},
-    Env,
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Ledger as _, LedgerInfo},
+    Address, Env, IntoVal, String, Symbol, TryFromVal, Val, Vec,
 };
 
 pub trait EnvTestUtils {
@@ -44,3 +54,198 @@ impl EnvTestUtils for Env {
         });
     }
 }
+
+/// Create transfer options with every optional behavior disabled. The new admin does not need to
+/// be verified, so any test address can be used as the new admin.
+pub fn default_transfer_options(e: &Env) -> TransferOptions {
+    TransferOptions {
+        kind: TargetKind::BlendPool,
+        allow_unverified: true,
+        allow_eoa: false,
+        receive_hook: ReceiveHook::Disabled,
+        policy: PolicySource::Global,
+        queued_reserves: QueuedReserveMode::Ignore,
+        actions: Vec::new(e),
+        escrow_status: EscrowStatus::Unchanged,
+        pool_status: 0,
+        timelock: 0,
+        expiry: 0,
+        memo: String::from_str(e, ""),
+        proposal: ProposalReference::None,
+        bind_proposal: false,
+        hashlock: Hashlock::None,
+    }
+}
+
+/// Create a config with no default timelock, a 30 day maximum expiry, no trusted factories,
+/// a random guardian and no cooldown
+pub fn default_config(e: &Env) -> Config {
+    Config {
+        default_timelock: 0,
+        max_expiry: 30 * ONE_DAY_LEDGERS,
+        trusted_factories: Vec::new(e),
+        guardian: Address::generate(e),
+        cooldown: 0,
+    }
+}
+
+/// Get the configuration of a Blend pool. The pool does not expose its configuration, so it is
+/// read from the pool's storage.
+pub fn get_pool_config(e: &Env, pool: &Address) -> PoolConfig {
+    e.as_contract(pool, || {
+        e.storage()
+            .instance()
+            .get(&Symbol::new(e, "Config"))
+            .unwrap()
+    })
+}
+
+/// A mock new admin contract that records the pools it receives
+#[contract]
+pub struct MockAdminReceiver;
+
+#[contractimpl]
+impl MockAdminReceiver {
+    /// Set whether `on_pool_admin_received` should fail
+    pub fn set_fail(e: Env, fail: bool) {
+        e.storage().instance().set(&symbol_short!("fail"), &fail);
+    }
+
+    /// Get the previous admin recorded for a received pool
+    pub fn received(e: Env, pool: Address) -> Option<Address> {
+        e.storage().instance().get(&pool)
+    }
+
+    pub fn on_pool_admin_received(e: Env, pool: Address, from: Address) {
+        let fail: bool = e
+            .storage()
+            .instance()
+            .get(&symbol_short!("fail"))
+            .unwrap_or(false);
+        if fail {
+            panic!("receive failed");
+        }
+        e.storage().instance().set(&pool, &from);
+    }
+}
+
+/// A mock policy contract that rejects a configurable set of actions
+#[contract]
+pub struct MockPolicy;
+
+#[contractimpl]
+impl MockPolicy {
+    /// Set whether an action should be rejected
+    pub fn set_reject(e: Env, action: TransferAction, reject: bool) {
+        e.storage().instance().set(&action, &reject);
+    }
+
+    pub fn check_transfer(
+        e: Env,
+        _pool: Address,
+        _cur_admin: Address,
+        _new_admin: Address,
+        action: TransferAction,
+    ) {
+        let reject: bool = e.storage().instance().get(&action).unwrap_or(false);
+        if reject {
+            panic!("action rejected");
+        }
+    }
+}
+
+/// A mock contract with a two-step admin handover
+#[contract]
+pub struct MockTwoStepAdmin;
+
+#[contractimpl]
+impl MockTwoStepAdmin {
+    pub fn initialize(e: Env, admin: Address) {
+        e.storage().instance().set(&symbol_short!("admin"), &admin);
+    }
+
+    pub fn admin(e: Env) -> Address {
+        e.storage().instance().get(&symbol_short!("admin")).unwrap()
+    }
+
+    pub fn propose_admin(e: Env, new_admin: Address) {
+        Self::admin(e.clone()).require_auth();
+        e.storage()
+            .instance()
+            .set(&symbol_short!("pending"), &new_admin);
+    }
+
+    pub fn accept_admin(e: Env) {
+        let pending: Address = e
+            .storage()
+            .instance()
+            .get(&symbol_short!("pending"))
+            .unwrap();
+        pending.require_auth();
+        e.storage()
+            .instance()
+            .set(&symbol_short!("admin"), &pending);
+        e.storage().instance().remove(&symbol_short!("pending"));
+    }
+}
+
+/// A mock governor contract with settable proposals
+#[contract]
+pub struct MockGovernor;
+
+#[contractimpl]
+impl MockGovernor {
+    /// Set a proposal that invokes a function on a contract when it executes
+    pub fn set_proposal(
+        e: Env,
+        proposal_id: u32,
+        contract_id: Address,
+        function: Symbol,
+        args: Vec<Val>,
+    ) {
+        let action: Val = ProposalAction::Calldata(Calldata {
+            contract_id,
+            function,
+            args,
+            auths: Vec::new(&e),
+        })
+        .into_val(&e);
+        let proposal = Proposal {
+            id: proposal_id,
+            config: ProposalConfig {
+                title: String::from_str(&e, "title"),
+                description: String::from_str(&e, "description"),
+                action: Vec::try_from_val(&e, &action).unwrap(),
+            },
+            data: ProposalData {
+                creator: e.current_contract_address(),
+                vote_start: 0,
+                vote_end: 0,
+                eta: 0,
+                status: ProposalStatus::Open,
+                executable: true,
+            },
+        };
+        e.storage().instance().set(&proposal_id, &proposal);
+    }
+
+    /// Set the status of a proposal
+    pub fn set_proposal_status(e: Env, proposal_id: u32, status: ProposalStatus) {
+        let mut proposal: Proposal = e.storage().instance().get(&proposal_id).unwrap();
+        proposal.data.status = status;
+        e.storage().instance().set(&proposal_id, &proposal);
+    }
+
+    /// Execute a proposal, invoking its calldata as the governor
+    pub fn execute(e: Env, proposal_id: u32) {
+        Self::set_proposal_status(e.clone(), proposal_id, ProposalStatus::Executed);
+        let proposal: Proposal = e.storage().instance().get(&proposal_id).unwrap();
+        let ProposalAction::Calldata(calldata) =
+            ProposalAction::try_from_val(&e, proposal.config.action.as_val()).unwrap();
+        e.invoke_contract::<Val>(&calldata.contract_id, &calldata.function, calldata.args);
+    }
+
+    pub fn get_proposal(e: Env, proposal_id: u32) -> Option<Proposal> {
+        e.storage().instance().get(&proposal_id)
+    }
+}
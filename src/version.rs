@@ -0,0 +1,49 @@
+use soroban_sdk::{contracttype, Env, String, Symbol, Vec};
+
+/// The version of the contract and the features it supports
+#[contracttype]
+#[derive(Clone)]
+pub struct VersionInfo {
+    /// The crate version the contract was built from
+    pub version: String,
+    /// The features supported by the contract
+    pub features: Vec<Symbol>,
+}
+
+/// The features supported by the contract. Must be updated whenever a feature is added.
+const FEATURES: [&str; 22] = [
+    "approved_admins",
+    "approved_wasms",
+    "receive_hook",
+    "policy",
+    "health_checks",
+    "queued_reserves",
+    "pool_actions",
+    "escrow_status",
+    "target_kinds",
+    "deploy_admin",
+    "upgrade",
+    "migrate",
+    "timelock",
+    "guardian",
+    "owner_transfer",
+    "cooldown",
+    "memo",
+    "bind_proposal",
+    "bond",
+    "hashlock",
+    "commit_reveal",
+    "candidates",
+];
+
+/// Get the version of the contract and the features it supports
+pub fn version_info(e: &Env) -> VersionInfo {
+    let mut features = Vec::new(e);
+    for feature in FEATURES.iter() {
+        features.push_back(Symbol::new(e, feature));
+    }
+    VersionInfo {
+        version: String::from_str(e, env!("CARGO_PKG_VERSION")),
+        features,
+    }
+}